@@ -0,0 +1,374 @@
+//! Inventories exported by logistics tools, besides the puzzle's own format.
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{check::Report, error::ParseError, input::numbered_lines};
+
+use crate::{parse_item, total::Total, CalorieLedger, Elf, ParseMode};
+
+/// Header of CSV inventories, optional.
+const CSV_HEADER: &str = "elf_id,item_calories";
+
+/// Layout of the inventory.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum InputFormat {
+    /// Detected from the first non-blank line, see `InputFormat::resolve`
+    #[default]
+    Auto,
+    /// Calories of one item per line, elves separated by blank lines, like the puzzle
+    Lines,
+    /// `elf_id,item_calories` rows, items of an elf don't need to be next to each other
+    Csv,
+    /// Array of elves, each one an array of calories of their items
+    Json,
+}
+
+impl InputFormat {
+    /// The format itself, or if it's `Auto`, the one the input looks like: JSON if it starts
+    /// with `[`, CSV if its first line is the header or a row with calories, lines otherwise.
+    pub fn resolve(self, input: &str) -> InputFormat {
+        if self != InputFormat::Auto {
+            return self;
+        }
+        match input.lines().map(str::trim).find(|line| !line.is_empty()) {
+            Some(line) if line.starts_with('[') => InputFormat::Json,
+            Some(line) if looks_like_csv(line) => InputFormat::Csv,
+            _ => InputFormat::Lines,
+        }
+    }
+}
+
+impl FromStr for InputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(InputFormat::Auto),
+            "lines" => Ok(InputFormat::Lines),
+            "csv" => Ok(InputFormat::Csv),
+            "json" => Ok(InputFormat::Json),
+            _ => Err(format!(
+                "unknown input format '{s}', expected 'auto', 'lines', 'csv' or 'json'"
+            )),
+        }
+    }
+}
+
+/// Field of a CSV row, without spaces and quotes around it.
+fn field(text: &str) -> &str {
+    text.trim().trim_matches('"')
+}
+
+/// Id and calories of a CSV row, if the line has a comma.
+fn split_row(line: &str) -> Option<(&str, &str)> {
+    line.split_once(',')
+        .map(|(id, calories)| (field(id), field(calories)))
+}
+
+fn is_header((id, calories): (&str, &str)) -> bool {
+    format!("{id},{calories}") == CSV_HEADER
+}
+
+/// Whether the line is the CSV header or a row with an id and calories of an item.
+fn looks_like_csv(line: &str) -> bool {
+    split_row(line).is_some_and(|(id, calories)| {
+        is_header((id, calories)) || (!id.is_empty() && calories.parse::<i64>().is_ok())
+    })
+}
+
+/// CSV rows read one line at a time, elves are numbered in the order of their first row.
+/// Only ids of elves are kept, so the items can be added up in a stream too.
+pub(crate) struct CsvRows {
+    mode: ParseMode,
+    positions: HashMap<String, usize>,
+    header_allowed: bool,
+}
+
+impl CsvRows {
+    pub(crate) fn new(mode: ParseMode) -> Self {
+        CsvRows {
+            mode,
+            positions: HashMap::new(),
+            header_allowed: true,
+        }
+    }
+
+    /// Position of the elf in the row, their id and calories of the item.
+    /// `None` for blank lines and the header. An elf first seen in this row is the next one,
+    /// at the position right after all elves before.
+    pub(crate) fn read<'a>(
+        &mut self,
+        line: &'a str,
+    ) -> Result<Option<(usize, &'a str, i64)>, ParseError> {
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        let row = split_row(line);
+        if std::mem::take(&mut self.header_allowed) && row.is_some_and(is_header) {
+            return Ok(None);
+        }
+
+        match row {
+            None => Err(ParseError::new(format!("expected '{CSV_HEADER}'"))),
+            Some(("", _)) => Err(ParseError::new("elf id can't be empty")),
+            Some((id, calories)) => {
+                let calories = parse_item(line, calories, self.mode)?;
+                let count = self.positions.len();
+                let position = *self.positions.entry(id.to_string()).or_insert(count);
+                Ok(Some((position, id, calories)))
+            }
+        }
+    }
+}
+
+/// Reads CSV rows, elves are ordered by their first row and keep their ids.
+pub(crate) fn read_csv<T: Total>(
+    input: &str,
+    mode: ParseMode,
+    report: &mut Report,
+) -> CalorieLedger<T> {
+    let mut elves = Vec::<Elf<T>>::new();
+    let mut rows = CsvRows::new(mode);
+
+    for (number, line) in numbered_lines(input) {
+        let result = rows.read(line).and_then(|row| match row {
+            Some((position, id, calories)) => {
+                if position == elves.len() {
+                    elves.push(Elf::new(position + 1, Some(id.to_string())));
+                }
+                elves[position].add(calories)
+            }
+            None => Ok(()),
+        });
+        if let Err(error) = result {
+            report.error(error.at_line(number, line));
+        }
+    }
+    CalorieLedger { elves }
+}
+
+/// Reads an array of elves, which are arrays of calories.
+/// The array is read whole, `serde_json` can't give elves one by one from a stream.
+pub(crate) fn read_json<T: Total>(
+    input: &str,
+    mode: ParseMode,
+    report: &mut Report,
+) -> CalorieLedger<T> {
+    let inventory = match serde_json::from_str::<Vec<Vec<i64>>>(input) {
+        Ok(inventory) => inventory,
+        Err(error) => {
+            report.error(json_error(input, &error));
+            return CalorieLedger::default();
+        }
+    };
+
+    let mut elves = vec![];
+    for (position, items) in inventory.into_iter().enumerate() {
+        let mut elf = Elf::new(position + 1, None);
+        for (item, calories) in items.into_iter().enumerate() {
+            let result = match calories < 0 && mode == ParseMode::Strict {
+                true => Err(at_item(
+                    input,
+                    position,
+                    item,
+                    ParseError::new(format!(
+                        "calories of item {} of elf {} can't be negative, found '{calories}'",
+                        item + 1,
+                        position + 1
+                    )),
+                )),
+                false => elf.add(calories),
+            };
+            if let Err(error) = result {
+                report.error(error);
+            }
+        }
+        elves.push(elf);
+    }
+    CalorieLedger { elves }
+}
+
+/// Points the error at the item of the elf, both counted from 0, in a valid inventory.
+fn at_item(input: &str, elf: usize, item: usize, error: ParseError) -> ParseError {
+    // the inventory was read as arrays of integers, so there are no strings to skip
+    let (mut depth, mut elves, mut items, mut expecting_item) = (0, 0, 0, false);
+    let mut offset = None;
+    for (index, byte) in input.bytes().enumerate() {
+        match byte {
+            b'[' => {
+                depth += 1;
+                if depth == 2 {
+                    (elves, items, expecting_item) = (elves + 1, 0, true);
+                }
+            }
+            b']' => depth -= 1,
+            b',' if depth == 2 => expecting_item = true,
+            b'-' | b'0'..=b'9' if depth == 2 && expecting_item => {
+                (items, expecting_item) = (items + 1, false);
+                if (elves, items) == (elf + 1, item + 1) {
+                    offset = Some(index);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Some(offset) = offset else {
+        return error;
+    };
+    let line_start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+    let number = input[..offset].matches('\n').count() + 1;
+    let line = input[line_start..].lines().next().unwrap_or_default();
+    error
+        .at_line(number, line)
+        .at_column(offset - line_start + 1)
+}
+
+/// Parse error pointing where `serde_json` gave up.
+fn json_error(input: &str, error: &serde_json::Error) -> ParseError {
+    // the message ends with the position, which the error has on its own
+    let message = error.to_string();
+    let message = message
+        .rsplit_once(" at line ")
+        .map_or(message.as_str(), |(message, _)| message);
+    let problem = ParseError::new(format!("invalid JSON inventory, {message}"));
+    match input.lines().nth(error.line().saturating_sub(1)) {
+        Some(line) if error.line() > 0 => problem
+            .at_line(error.line(), line)
+            .at_column(error.column().max(1)),
+        _ => problem,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Error;
+
+    use crate::{formats::InputFormat, CalorieLedger, ParseMode};
+
+    /// Index, id, items and total of every elf.
+    fn elves(ledger: &CalorieLedger) -> Vec<(usize, Option<&str>, Vec<i64>, i64)> {
+        ledger
+            .elves()
+            .iter()
+            .map(|elf| (elf.index(), elf.id(), elf.items().to_vec(), elf.total()))
+            .collect()
+    }
+
+    #[test]
+    fn format_is_detected_from_the_first_line() {
+        let inputs = [
+            "\n[[1]]",
+            "elf_id,item_calories\n",
+            "a,1\n",
+            " \"a b\", -1\n",
+            "1000\n\n2000\n",
+            "1,x\n",
+            ",1\n",
+            "hello, world\n",
+            "",
+        ];
+        let expected_formats = [
+            InputFormat::Json,
+            InputFormat::Csv,
+            InputFormat::Csv,
+            InputFormat::Csv,
+            InputFormat::Lines,
+            InputFormat::Lines,
+            InputFormat::Lines,
+            InputFormat::Lines,
+            InputFormat::Lines,
+        ];
+
+        for (input, format) in inputs.iter().zip(expected_formats) {
+            assert_eq!(InputFormat::Auto.resolve(input), format, "{input:?}");
+        }
+        assert_eq!(InputFormat::Csv.resolve("1000\n"), InputFormat::Csv);
+    }
+
+    #[test]
+    fn every_format_gives_the_same_ledger() {
+        let inputs = [
+            "1000\n2000\n\n4000\n",
+            "elf_id,item_calories\nfirst,1000\nsecond,4000\n\"first\", 2000\n",
+            "[[1000, 2000], [4000]]",
+        ];
+        let expected_ids = [[None, None], [Some("first"), Some("second")], [None, None]];
+
+        for (input, ids) in inputs.iter().zip(expected_ids) {
+            let ledger = CalorieLedger::parse(input, ParseMode::Strict).unwrap();
+            assert_eq!(
+                elves(&ledger),
+                vec![
+                    (1, ids[0], vec![1000, 2000], 3000),
+                    (2, ids[1], vec![4000], 4000)
+                ],
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_rows_and_items_are_rejected() {
+        let inputs = [
+            ("elf_id,item_calories\na,1\nb\n", InputFormat::Csv),
+            ("a,1\n,2\n", InputFormat::Csv),
+            ("a,1\nb,x\n", InputFormat::Csv),
+            ("a,-1\n", InputFormat::Csv),
+            ("[[1], [2, -3]]", InputFormat::Json),
+            ("[\n  [1],\n  [ 2 ,-3]\n]", InputFormat::Json),
+            ("[[1],\n [2, \"x\"]]", InputFormat::Json),
+            ("[1, 2]", InputFormat::Auto),
+        ];
+        let expected_errors = [
+            (Some(3), None, "expected 'elf_id,item_calories'"),
+            (Some(2), None, "elf id can't be empty"),
+            (Some(2), Some(3), "expected calories of an item, found 'x'"),
+            (
+                Some(1),
+                Some(3),
+                "calories of an item can't be negative, found '-1'",
+            ),
+            (
+                Some(1),
+                Some(11),
+                "calories of item 2 of elf 2 can't be negative, found '-3'",
+            ),
+            (
+                Some(3),
+                Some(8),
+                "calories of item 2 of elf 2 can't be negative, found '-3'",
+            ),
+            (
+                Some(2),
+                Some(8),
+                "invalid JSON inventory, invalid type: string \"x\", expected i64",
+            ),
+            (
+                Some(1),
+                Some(2),
+                "invalid JSON inventory, invalid type: integer `1`, expected a sequence",
+            ),
+        ];
+
+        for ((input, format), (line, column, message)) in inputs.iter().zip(expected_errors) {
+            match CalorieLedger::<i64>::parse_as(input, *format, ParseMode::Strict) {
+                Err(Error::Parse(error)) => {
+                    assert_eq!((error.line, error.column), (line, column), "{input:?}");
+                    assert_eq!(error.message, message);
+                }
+                other => panic!("expected parse error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn negative_items_are_accepted_in_lenient_mode() {
+        let inputs = ["a,-1\na,3\n", "[[-1, 3]]"];
+        for input in inputs {
+            let ledger = CalorieLedger::<i64>::parse(input, ParseMode::Lenient).unwrap();
+            assert_eq!(ledger.elves()[0].total(), 2);
+        }
+    }
+}
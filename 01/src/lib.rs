@@ -0,0 +1,523 @@
+pub mod formats;
+pub mod stats;
+pub mod total;
+
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use aoc_common::{
+    check::{Diagnostic, Report},
+    error::ParseError,
+    input::{numbered_lines, parse_token, stream_lines},
+    Answer, Error, Result, Solution, StreamingSolution,
+};
+use formats::{CsvRows, InputFormat};
+use total::Total;
+
+/// Totals the answers are computed with, exact for any input with the `bigint` feature.
+#[cfg(not(feature = "bigint"))]
+pub type DayTotal = i64;
+/// Totals the answers are computed with, exact for any input with the `bigint` feature.
+#[cfg(feature = "bigint")]
+pub type DayTotal = num_bigint::BigInt;
+
+/// How lines that are not calories of an item are treated.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ParseMode {
+    /// Only blank lines separate elves, any other line must be a non-negative number
+    #[default]
+    Strict,
+    /// Every line that's not a number separates elves, negative numbers are items too,
+    /// in every format
+    Lenient,
+}
+
+/// Food carried by a single elf.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Elf<T = i64> {
+    index: usize,
+    id: Option<String>,
+    items: Vec<i64>,
+    total: T,
+}
+
+impl<T: Total> Elf<T> {
+    fn new(index: usize, id: Option<String>) -> Self {
+        Elf {
+            index,
+            id,
+            items: vec![],
+            total: T::default(),
+        }
+    }
+
+    /// Position of the elf in the input, counted from 1.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Id of the elf in the inventory, only CSV inventories have them.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Calories of every item, in the order of the input.
+    pub fn items(&self) -> &[i64] {
+        &self.items
+    }
+
+    /// Calories of all items, checked to fit in `T` when the elf was read.
+    pub fn total(&self) -> T {
+        self.total.clone()
+    }
+
+    fn add(&mut self, calories: i64) -> std::result::Result<(), ParseError> {
+        self.total = add_item(&self.total, calories)?;
+        self.items.push(calories);
+        Ok(())
+    }
+}
+
+/// Total of an elf with calories of another item, if it still fits in `T`.
+fn add_item<T: Total>(total: &T, calories: i64) -> std::result::Result<T, ParseError> {
+    total.checked_plus(&T::from(calories)).ok_or_else(|| {
+        ParseError::new(format!(
+            "total calories of the elf don't fit in {}",
+            T::LIMIT
+        ))
+    })
+}
+
+/// Elf as named in text, by their id if they have one, otherwise by position.
+pub fn elf_name(index: usize, id: Option<&str>) -> String {
+    id.map_or_else(|| index.to_string(), str::to_string)
+}
+
+/// Every elf of the expedition with their food, in the order of the input.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CalorieLedger<T = i64> {
+    elves: Vec<Elf<T>>,
+}
+
+impl<T: Total> CalorieLedger<T> {
+    /// Reads the inventory in any of the formats, detected from the input.
+    pub fn parse(input: &str, mode: ParseMode) -> Result<Self> {
+        Self::parse_as(input, InputFormat::Auto, mode)
+    }
+
+    pub fn parse_as(input: &str, format: InputFormat, mode: ParseMode) -> Result<Self> {
+        let mut report = Report::default();
+        let ledger = read_ledger(input, format, mode, &mut report);
+        report.into_result(ledger)
+    }
+
+    pub fn elves(&self) -> &[Elf<T>] {
+        &self.elves
+    }
+
+    /// `n` elves with the largest totals, found with a heap of `n` elves in O(m log n).
+    /// Of elves with equal totals, the ones earlier in the input are ranked higher.
+    pub fn top_n(&self, n: usize) -> TopElves<'_, T> {
+        if n == 0 {
+            return TopElves::default();
+        }
+
+        // the root of the heap is the worst of the elves kept so far
+        let mut heap = BinaryHeap::with_capacity(n + 1);
+        for (position, elf) in self.elves.iter().enumerate() {
+            heap.push(Reverse((&elf.total, Reverse(position))));
+            if heap.len() > n {
+                heap.pop();
+            }
+        }
+        let top = heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, Reverse(position)))| &self.elves[position])
+            .collect::<Vec<&Elf<T>>>();
+
+        // elves with the same total as the last one, that didn't fit
+        let tied = match top.last() {
+            Some(last) if top.len() == n => self
+                .elves
+                .iter()
+                .filter(|elf| elf.total == last.total && elf.index > last.index)
+                .collect(),
+            _ => vec![],
+        };
+        TopElves { top, tied }
+    }
+}
+
+/// Elves with the largest totals, see `CalorieLedger::top_n`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TopElves<'a, T = i64> {
+    /// From the largest total
+    pub top: Vec<&'a Elf<T>>,
+    /// Elves left out, with the same total as the last one in the top
+    pub tied: Vec<&'a Elf<T>>,
+}
+
+impl<T: Total> TopElves<'_, T> {
+    /// Sum of totals of the top elves, if it fits in `T`.
+    pub fn checked_sum(&self) -> Option<T> {
+        self.top
+            .iter()
+            .try_fold(T::default(), |sum, elf| sum.checked_plus(&elf.total))
+    }
+}
+
+/// Groups lines into elves one at a time, so the ledger can be read from a stream too.
+struct ElfGrouper<T> {
+    mode: ParseMode,
+    current: Option<Elf<T>>,
+    count: usize,
+}
+
+impl<T: Total> ElfGrouper<T> {
+    fn new(mode: ParseMode) -> Self {
+        ElfGrouper {
+            mode,
+            current: None,
+            count: 0,
+        }
+    }
+
+    /// Takes the next line, returns the elf it finished, if any.
+    /// An invalid line is skipped, the elf stays as it was.
+    fn push(&mut self, line: &str) -> std::result::Result<Option<Elf<T>>, ParseError> {
+        let text = line.trim();
+        let calories = match self.mode {
+            ParseMode::Strict => {
+                if text.is_empty() {
+                    return Ok(self.current.take());
+                }
+                parse_item(line, text, self.mode)?
+            }
+            ParseMode::Lenient => match text.parse::<i64>() {
+                Ok(calories) => calories,
+                Err(_) => return Ok(self.current.take()),
+            },
+        };
+
+        let elf = self.current.get_or_insert_with(|| {
+            self.count += 1;
+            Elf::new(self.count, None)
+        });
+        elf.add(calories)?;
+        Ok(None)
+    }
+
+    fn finish(self) -> Option<Elf<T>> {
+        self.current
+    }
+}
+
+/// Calories of an item at `text` in the line, only lenient mode accepts negative ones.
+fn parse_item(line: &str, text: &str, mode: ParseMode) -> std::result::Result<i64, ParseError> {
+    let calories = parse_token::<i64>(line, text, "calories of an item")?;
+    if calories < 0 && mode == ParseMode::Strict {
+        return Err(ParseError::new(format!(
+            "calories of an item can't be negative, found '{text}'"
+        ))
+        .at_token(line, text));
+    }
+    Ok(calories)
+}
+
+/// Reads the ledger, reporting every invalid line or item and skipping it.
+fn read_ledger<T: Total>(
+    input: &str,
+    format: InputFormat,
+    mode: ParseMode,
+    report: &mut Report,
+) -> CalorieLedger<T> {
+    match format.resolve(input) {
+        InputFormat::Csv => formats::read_csv(input, mode, report),
+        InputFormat::Json => formats::read_json(input, mode, report),
+        _ => read_lines(input, mode, report),
+    }
+}
+
+/// Reads elves separated by blank lines, or any non-numeric ones in lenient mode.
+fn read_lines<T: Total>(input: &str, mode: ParseMode, report: &mut Report) -> CalorieLedger<T> {
+    let mut grouper = ElfGrouper::new(mode);
+    let mut elves = vec![];
+    for (number, line) in numbered_lines(input) {
+        match grouper.push(line) {
+            Ok(elf) => elves.extend(elf),
+            Err(error) => report.error(error.at_line(number, line)),
+        }
+    }
+    elves.extend(grouper.finish());
+    CalorieLedger { elves }
+}
+
+fn top_three_overflow() -> Error {
+    Error::parse(format!(
+        "sum of the three largest totals doesn't fit in {}",
+        DayTotal::LIMIT
+    ))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = CalorieLedger<DayTotal>;
+
+    fn parse(input: &str) -> Result<CalorieLedger<DayTotal>> {
+        let ledger = CalorieLedger::parse(input, ParseMode::Strict)?;
+        // part 2 adds up the top three, it must not overflow either
+        ledger
+            .top_n(3)
+            .checked_sum()
+            .ok_or_else(top_three_overflow)?;
+        Ok(ledger)
+    }
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        let ledger =
+            read_ledger::<DayTotal>(input, InputFormat::Auto, ParseMode::Strict, &mut report);
+        if ledger.top_n(3).checked_sum().is_none() {
+            report.error(Diagnostic::from(top_three_overflow()).problem);
+        }
+        report.into_diagnostics()
+    }
+
+    fn part1(ledger: &CalorieLedger<DayTotal>) -> Answer {
+        ledger
+            .top_n(1)
+            .top
+            .first()
+            .map(|elf| elf.total())
+            .unwrap_or_default()
+            .into_answer()
+    }
+
+    fn part2(ledger: &CalorieLedger<DayTotal>) -> Answer {
+        ledger
+            .top_n(3)
+            .checked_sum()
+            .unwrap_or_default()
+            .into_answer()
+    }
+}
+
+/// The three largest totals seen so far, largest first.
+#[derive(Debug, Default)]
+struct TopThree(Vec<DayTotal>);
+
+impl TopThree {
+    fn keep(&mut self, total: DayTotal) {
+        let position = self.0.partition_point(|kept| *kept >= total);
+        self.0.insert(position, total);
+        self.0.truncate(3);
+    }
+
+    fn into_answers(self) -> Result<[Answer; 2]> {
+        let sum = self
+            .0
+            .iter()
+            .try_fold(DayTotal::default(), |sum, total| sum.checked_plus(total))
+            .ok_or_else(top_three_overflow)?;
+        let largest = self.0.into_iter().next().unwrap_or_default();
+        Ok([largest.into_answer(), sum.into_answer()])
+    }
+}
+
+/// Adds up items of a CSV inventory from the first non-blank line on, only totals are kept.
+fn solve_csv_stream(
+    first: (usize, String),
+    lines: impl Iterator<Item = Result<(usize, String)>>,
+) -> Result<[Answer; 2]> {
+    let mut rows = CsvRows::new(ParseMode::Strict);
+    let mut totals = Vec::<DayTotal>::new();
+    for line in std::iter::once(Ok(first)).chain(lines) {
+        let (number, line) = line?;
+        let row = rows.read(&line).and_then(|row| {
+            let Some((position, _, calories)) = row else {
+                return Ok(());
+            };
+            if position == totals.len() {
+                totals.push(DayTotal::default());
+            }
+            totals[position] = add_item(&totals[position], calories)?;
+            Ok(())
+        });
+        row.map_err(|error| error.at_line(number, &line))?;
+    }
+
+    let mut top_three = TopThree::default();
+    totals.into_iter().for_each(|total| top_three.keep(total));
+    top_three.into_answers()
+}
+
+impl StreamingSolution for Day01 {
+    fn solve_stream<R: BufRead>(reader: R) -> Result<[Answer; 2]> {
+        let mut top_three = TopThree::default();
+        let mut keep = |elf: Elf<DayTotal>| top_three.keep(elf.total);
+
+        let mut grouper = ElfGrouper::new(ParseMode::Strict);
+        // blank lines before the first one that shows the format
+        let mut leading = Some(String::new());
+        let mut lines = stream_lines(reader);
+        while let Some(line) = lines.next() {
+            let (number, line) = line?;
+            if let Some(text) = &mut leading {
+                if line.trim().is_empty() {
+                    text.push_str(&line);
+                    text.push('\n');
+                    continue;
+                }
+                match InputFormat::Auto.resolve(&line) {
+                    InputFormat::Csv => return solve_csv_stream((number, line), lines),
+                    InputFormat::Json => {
+                        // JSON inventories are read whole, see `formats::read_json`
+                        let mut text = text.clone() + &line + "\n";
+                        for line in lines {
+                            text.push_str(&line?.1);
+                            text.push('\n');
+                        }
+                        let ledger = Self::parse(&text)?;
+                        return Ok([Self::part1(&ledger), Self::part2(&ledger)]);
+                    }
+                    _ => leading = None,
+                }
+            }
+            let elf = grouper
+                .push(&line)
+                .map_err(|error| error.at_line(number, &line))?;
+            elf.into_iter().for_each(&mut keep);
+        }
+        grouper.finish().into_iter().for_each(keep);
+        top_three.into_answers()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Error, Solution};
+
+    use crate::{CalorieLedger, Day01, Elf, ParseMode, TopElves};
+
+    /// Index, items and total of every elf.
+    fn elves(ledger: &CalorieLedger) -> Vec<(usize, Vec<i64>, i64)> {
+        ledger
+            .elves()
+            .iter()
+            .map(|elf| (elf.index(), elf.items().to_vec(), elf.total()))
+            .collect()
+    }
+
+    #[test]
+    fn elves_are_separated_by_blank_lines() {
+        let ledger =
+            CalorieLedger::parse("1000\n 2000 \n\n\n4000\n  \n5000\n6000", ParseMode::Strict)
+                .unwrap();
+        assert_eq!(
+            elves(&ledger),
+            vec![
+                (1, vec![1000, 2000], 3000),
+                (2, vec![4000], 4000),
+                (3, vec![5000, 6000], 11000)
+            ]
+        );
+    }
+
+    #[test]
+    fn garbage_lines_are_rejected_in_strict_mode() {
+        let inputs = ["100\n12a4\n300\n", "100\n\n-5\n", "100\n\n\nx\n"];
+        let expected_errors = [
+            (2, 1, "expected calories of an item, found '12a4'"),
+            (3, 1, "calories of an item can't be negative, found '-5'"),
+            (4, 1, "expected calories of an item, found 'x'"),
+        ];
+
+        for (input, (line, column, message)) in inputs.iter().zip(expected_errors) {
+            match Day01::parse(input) {
+                Err(Error::Parse(error)) => {
+                    assert_eq!((error.line, error.column), (Some(line), Some(column)));
+                    assert_eq!(error.message, message);
+                }
+                other => panic!("expected parse error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn elves_are_separated_by_non_numeric_lines_in_lenient_mode() {
+        let ledger =
+            CalorieLedger::parse("1\n 2\r\n\n-1\n\n\n4\nx\n5 ", ParseMode::Lenient).unwrap();
+        assert_eq!(
+            elves(&ledger),
+            vec![
+                (1, vec![1, 2], 3),
+                (2, vec![-1], -1),
+                (3, vec![4], 4),
+                (4, vec![5], 5)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bigint"))]
+    fn overflowing_totals_are_rejected() {
+        let inputs = ["9223372036854775807\n1\n", "9223372036854775807\n\n1\n"];
+        let expected_messages = [
+            "total calories of the elf don't fit in 64 bits",
+            "sum of the three largest totals doesn't fit in 64 bits",
+        ];
+
+        for (input, message) in inputs.iter().zip(expected_messages) {
+            match Day01::parse(input) {
+                Err(Error::Parse(error)) => assert_eq!(error.message, message),
+                other => panic!("expected parse error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn totals_beyond_64_bits_are_exact() {
+        use aoc_common::{Answer, StreamingSolution};
+
+        let input = "9223372036854775807\n1\n\n9223372036854775807\n\n5\n";
+        let expected = [
+            Answer::from("9223372036854775808"),
+            Answer::from("18446744073709551620"),
+        ];
+
+        let ledger = Day01::parse(input).unwrap();
+        assert_eq!([Day01::part1(&ledger), Day01::part2(&ledger)], expected);
+        assert_eq!(Day01::solve_stream(input.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn top_elves_are_ranked_with_ties_at_the_cutoff() {
+        let ledger =
+            CalorieLedger::parse("5\n\n9\n\n7\n\n9\n\n7\n\n1\n\n7\n", ParseMode::Strict).unwrap();
+        let ranked = |elves: &[&Elf]| {
+            elves
+                .iter()
+                .map(|elf| (elf.index(), elf.total()))
+                .collect::<Vec<_>>()
+        };
+
+        let top = ledger.top_n(3);
+        assert_eq!(ranked(&top.top), vec![(2, 9), (4, 9), (3, 7)]);
+        assert_eq!(ranked(&top.tied), vec![(5, 7), (7, 7)]);
+
+        let top = ledger.top_n(2);
+        assert_eq!(ranked(&top.top), vec![(2, 9), (4, 9)]);
+        assert_eq!(ranked(&top.tied), vec![]);
+
+        // everyone fits, nobody is left out
+        let top = ledger.top_n(10);
+        assert_eq!(top.top.len(), 7);
+        assert_eq!(ranked(&top.tied), vec![]);
+
+        assert_eq!(ledger.top_n(0), TopElves::default());
+    }
+}
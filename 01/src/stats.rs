@@ -0,0 +1,318 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{elf_name, total::Total, CalorieLedger, Elf};
+
+/// Percentiles of totals reported besides the median.
+pub const PERCENTILES: [u8; 4] = [10, 25, 75, 90];
+
+/// Most bins of the histogram, fewer if there are fewer elves.
+const HISTOGRAM_BINS: usize = 10;
+/// Length of the longest bar in the text histogram.
+const BAR_WIDTH: usize = 40;
+
+/// Single item, with the elf carrying it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Snack {
+    pub elf: usize,
+    /// Id of the elf, only CSV inventories have them
+    pub id: Option<String>,
+    pub calories: i64,
+}
+
+/// Elf standing out with their total.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ElfTotal {
+    pub elf: usize,
+    /// Id of the elf, only CSV inventories have them
+    pub id: Option<String>,
+    pub total: i128,
+}
+
+/// Range of totals in the histogram, with number of elves whose total falls into it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Bin {
+    /// First total in the bin
+    pub start: i128,
+    /// Last total in the bin, inclusive
+    pub end: i128,
+    pub elves: usize,
+}
+
+/// Summary of the whole inventory of the expedition.
+/// Totals are `i128`, which fits any total with `Total` of any width.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CalorieStats {
+    pub elves: usize,
+    pub items: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Totals at each of `PERCENTILES`, by the nearest-rank method
+    pub percentiles: Vec<(u8, i128)>,
+    /// Elf with the smallest total, the first one if there are several
+    pub smallest: ElfTotal,
+    /// Elf with the largest total, the first one if there are several
+    pub largest: ElfTotal,
+    /// Number of elves carrying given number of items, by number of items
+    pub item_counts: Vec<(usize, usize)>,
+    pub largest_snack: Snack,
+    pub histogram: Vec<Bin>,
+}
+
+impl CalorieStats {
+    /// Statistics of the ledger, or `None` if there are no elves.
+    pub fn new<T: Total>(ledger: &CalorieLedger<T>) -> Option<Self> {
+        let elves = ledger.elves();
+        let first = elves.first()?;
+
+        let total = |elf: &Elf<T>| elf.total.to_i128();
+        let mut totals = elves.iter().map(total).collect::<Vec<i128>>();
+        totals.sort_unstable();
+        let count = totals.len();
+        // the sum of all totals is a sum of i64 items too, so it fits as well
+        let sum = totals.iter().sum::<i128>();
+        let median = match count % 2 {
+            1 => totals[count / 2] as f64,
+            _ => (totals[count / 2 - 1] + totals[count / 2]) as f64 / 2.0,
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let rank = (usize::from(percentile) * count).div_ceil(100).max(1);
+                (percentile, totals[rank - 1])
+            })
+            .collect();
+
+        let by_total = |elf: &&Elf<T>| total(elf);
+        // `max_by_key` picks the last of equal elves, `min_by_key` the first one
+        let smallest = elves.iter().min_by_key(by_total).unwrap_or(first);
+        let largest = elves.iter().rev().max_by_key(by_total).unwrap_or(first);
+
+        let mut item_counts = BTreeMap::new();
+        for elf in elves {
+            *item_counts.entry(elf.items().len()).or_insert(0) += 1;
+        }
+        let (snack_elf, calories) = elves
+            .iter()
+            .flat_map(|elf| elf.items().iter().map(move |&calories| (elf, calories)))
+            .rev()
+            .max_by_key(|&(_, calories)| calories)
+            .unwrap_or((first, 0));
+        let elf_total = |elf: &Elf<T>| ElfTotal {
+            elf: elf.index(),
+            id: elf.id.clone(),
+            total: total(elf),
+        };
+
+        Some(CalorieStats {
+            elves: count,
+            items: elves.iter().map(|elf| elf.items().len()).sum(),
+            mean: sum as f64 / count as f64,
+            median,
+            percentiles,
+            smallest: elf_total(smallest),
+            largest: elf_total(largest),
+            item_counts: item_counts.into_iter().collect(),
+            largest_snack: Snack {
+                elf: snack_elf.index(),
+                id: snack_elf.id.clone(),
+                calories,
+            },
+            histogram: histogram(&totals),
+        })
+    }
+}
+
+/// Histogram of sorted totals, in bins of equal width between the smallest and largest one.
+fn histogram(totals: &[i128]) -> Vec<Bin> {
+    let (Some(&min), Some(&max)) = (totals.first(), totals.last()) else {
+        return vec![];
+    };
+    // like the totals, their span is far from the limits of i128
+    let span = max - min + 1;
+    let most_bins = HISTOGRAM_BINS.min(totals.len()) as i128;
+    let width = (span + most_bins - 1) / most_bins;
+    // wide bins may cover all totals with fewer of them
+    let bin_count = (span + width - 1) / width;
+
+    let mut bins = (0..bin_count)
+        .map(|bin| {
+            let start = min + bin * width;
+            Bin {
+                start,
+                end: (start + width - 1).min(max),
+                elves: 0,
+            }
+        })
+        .collect::<Vec<Bin>>();
+    for &total in totals {
+        let bin = (total - min) / width;
+        bins[bin as usize].elves += 1;
+    }
+    bins
+}
+
+impl fmt::Display for CalorieStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "elves: {}, items: {} ({:.1} per elf)",
+            self.elves,
+            self.items,
+            self.items as f64 / self.elves as f64
+        )?;
+        writeln!(f, "mean total: {:.1}, median: {}", self.mean, self.median)?;
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(percentile, total)| format!("p{percentile} {total}"))
+            .collect::<Vec<String>>();
+        writeln!(f, "percentiles: {}", percentiles.join(", "))?;
+        writeln!(
+            f,
+            "smallest total: {} (elf {}), largest: {} (elf {})",
+            self.smallest.total,
+            elf_name(self.smallest.elf, self.smallest.id.as_deref()),
+            self.largest.total,
+            elf_name(self.largest.elf, self.largest.id.as_deref())
+        )?;
+        writeln!(
+            f,
+            "largest snack: {} calories (elf {})",
+            self.largest_snack.calories,
+            elf_name(self.largest_snack.elf, self.largest_snack.id.as_deref())
+        )?;
+        let item_counts = self
+            .item_counts
+            .iter()
+            .map(|(items, elves)| format!("{items}: {elves}"))
+            .collect::<Vec<String>>();
+        writeln!(f, "elves by number of items: {}", item_counts.join(", "))?;
+
+        writeln!(f, "totals:")?;
+        let edge_width = self
+            .histogram
+            .iter()
+            .map(|bin| bin.start.to_string().len().max(bin.end.to_string().len()))
+            .max()
+            .unwrap_or(0);
+        let most = self
+            .histogram
+            .iter()
+            .map(|bin| bin.elves)
+            .max()
+            .unwrap_or(0);
+        for bin in &self.histogram {
+            // every bin with elves gets at least one mark
+            let bar = (bin.elves * BAR_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>edge_width$} - {:>edge_width$} | {} {}",
+                bin.start,
+                bin.end,
+                "#".repeat(bar),
+                bin.elves
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        stats::{Bin, CalorieStats, ElfTotal, Snack},
+        CalorieLedger, ParseMode,
+    };
+
+    fn stats(input: &str) -> CalorieStats {
+        CalorieStats::new(&CalorieLedger::<i64>::parse(input, ParseMode::Strict).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn stats_summarize_the_ledger() {
+        let stats = stats("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n");
+
+        assert_eq!(stats.elves, 5);
+        assert_eq!(stats.items, 10);
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(
+            stats.percentiles,
+            vec![(10, 4000), (25, 6000), (75, 11000), (90, 24000)]
+        );
+        assert_eq!(
+            stats.smallest,
+            ElfTotal {
+                elf: 2,
+                id: None,
+                total: 4000
+            }
+        );
+        assert_eq!(
+            stats.largest,
+            ElfTotal {
+                elf: 4,
+                id: None,
+                total: 24000
+            }
+        );
+        assert_eq!(stats.item_counts, vec![(1, 2), (2, 1), (3, 2)]);
+        assert_eq!(
+            stats.largest_snack,
+            Snack {
+                elf: 5,
+                id: None,
+                calories: 10000
+            }
+        );
+        assert_eq!(
+            stats.histogram.iter().map(|bin| bin.elves).sum::<usize>(),
+            5
+        );
+        assert_eq!(
+            stats.histogram.first(),
+            Some(&Bin {
+                start: 4000,
+                end: 8000,
+                elves: 2
+            })
+        );
+        assert_eq!(stats.histogram.last().map(|bin| bin.end), Some(24000));
+    }
+
+    #[test]
+    fn median_of_even_number_of_elves_is_between_the_middle_ones() {
+        assert_eq!(stats("1\n\n2\n\n4\n\n8\n").median, 3.0);
+    }
+
+    #[test]
+    fn stats_are_written_as_text() {
+        assert_eq!(
+            stats("5\n\n5\n1\n\n8\n").to_string(),
+            "elves: 3, items: 4 (1.3 per elf)\n\
+             mean total: 6.3, median: 6\n\
+             percentiles: p10 5, p25 5, p75 8, p90 8\n\
+             smallest total: 5 (elf 1), largest: 8 (elf 3)\n\
+             largest snack: 8 calories (elf 3)\n\
+             elves by number of items: 1: 2, 2: 1\n\
+             totals:\n\
+             5 - 6 | ######################################## 2\n\
+             7 - 8 | #################### 1\n"
+        );
+    }
+
+    #[test]
+    fn elves_are_named_by_their_ids() {
+        let stats = stats("elf_id,item_calories\nE7,5\nE3,8\nE7,1\n");
+        assert_eq!(stats.largest.id.as_deref(), Some("E3"));
+        assert!(stats.to_string().contains(
+            "smallest total: 6 (elf E7), largest: 8 (elf E3)\nlargest snack: 8 calories (elf E3)\n"
+        ));
+    }
+
+    #[test]
+    fn there_are_no_stats_without_elves() {
+        let ledger = CalorieLedger::<i64>::parse("\n\n", ParseMode::Strict).unwrap();
+        assert_eq!(CalorieStats::new(&ledger), None);
+    }
+}
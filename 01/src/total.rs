@@ -0,0 +1,55 @@
+//! Numbers the calories of an elf are added up in.
+
+use aoc_common::Answer;
+
+/// Total calories, `i64` rejects totals that don't fit, `BigInt` (with the `bigint` feature) is exact.
+pub trait Total: Ord + Clone + Default + From<i64> {
+    /// What totals have to fit in, for errors of the ones that don't.
+    const LIMIT: &'static str;
+
+    /// Sum of both, `None` if it doesn't fit.
+    fn checked_plus(&self, other: &Self) -> Option<Self>;
+
+    /// Answer of the puzzle, as text if it's too large for a number.
+    fn into_answer(self) -> Answer;
+
+    /// The total as `i128`, which fits a sum of any number of `i64` items that fit in memory.
+    fn to_i128(&self) -> i128;
+}
+
+impl Total for i64 {
+    const LIMIT: &'static str = "64 bits";
+
+    fn checked_plus(&self, other: &Self) -> Option<Self> {
+        self.checked_add(*other)
+    }
+
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+
+    fn to_i128(&self) -> i128 {
+        i128::from(*self)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Total for num_bigint::BigInt {
+    const LIMIT: &'static str = "memory";
+
+    fn checked_plus(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn into_answer(self) -> Answer {
+        match i64::try_from(&self) {
+            Ok(number) => number.into(),
+            Err(_) => self.to_string().into(),
+        }
+    }
+
+    fn to_i128(&self) -> i128 {
+        // items are read from `i64`, there can't be enough of them to get past `i128`
+        i128::try_from(self).unwrap()
+    }
+}
@@ -1,4 +1,11 @@
-use std::{fs, str::FromStr};
+use std::{fmt, io::BufRead, str::FromStr};
+
+use aoc_common::{
+    check::{check_lines, Diagnostic, Report},
+    error::ParseError,
+    input::{parse_lines, stream_parsed_lines},
+    Answer, Solution, StreamingSolution,
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum GameChoice {
@@ -8,14 +15,14 @@ enum GameChoice {
 }
 
 impl FromStr for GameChoice {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" | "X" => Ok(GameChoice::Rock),
             "B" | "Y" => Ok(GameChoice::Paper),
             "C" | "Z" => Ok(GameChoice::Scissors),
-            _ => Err(String::from(s)),
+            _ => Err(ParseError::new(format!("unknown choice '{s}'"))),
         }
     }
 }
@@ -28,18 +35,109 @@ enum GameResult {
 }
 
 impl FromStr for GameResult {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(GameResult::Loss),
             "Y" => Ok(GameResult::Draw),
             "Z" => Ok(GameResult::Victory),
-            _ => Err(String::from(s)),
+            _ => Err(ParseError::new(format!("unknown game result '{s}'"))),
         }
     }
 }
 
+/// Second column of the strategy guide.
+/// It's either my choice (first part), or the expected result of the round (second part).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    fn as_choice(self) -> GameChoice {
+        match self {
+            Response::X => GameChoice::Rock,
+            Response::Y => GameChoice::Paper,
+            Response::Z => GameChoice::Scissors,
+        }
+    }
+
+    fn as_result(self) -> GameResult {
+        match self {
+            Response::X => GameResult::Loss,
+            Response::Y => GameResult::Draw,
+            Response::Z => GameResult::Victory,
+        }
+    }
+}
+
+impl FromStr for Response {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Response::X),
+            "Y" => Ok(Response::Y),
+            "Z" => Ok(Response::Z),
+            _ => Err(ParseError::new(format!(
+                "unknown response '{s}', expected X, Y or Z"
+            ))),
+        }
+    }
+}
+
+/// Single line of the strategy guide.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Round {
+    opponent: GameChoice,
+    response: Response,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<&str>>()[..] {
+            [opponent, response] => {
+                if !matches!(opponent, "A" | "B" | "C") {
+                    return Err(ParseError::new(format!(
+                        "unknown opponent's choice '{opponent}', expected A, B or C"
+                    ))
+                    .at_token(s, opponent));
+                }
+
+                Ok(Round {
+                    opponent: GameChoice::from_str(opponent)?,
+                    response: Response::from_str(response)
+                        .map_err(|error| error.at_token(s, response))?,
+                })
+            }
+            _ => Err(ParseError::new(
+                "expected opponent's choice and response separated by a space",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let opponent = match self.opponent {
+            GameChoice::Rock => 'A',
+            GameChoice::Paper => 'B',
+            GameChoice::Scissors => 'C',
+        };
+        write!(f, "{opponent} {:?}", self.response)
+    }
+}
+
+/// Writes the rounds back as a strategy guide.
+pub fn serialize_strategy(rounds: &[Round]) -> String {
+    rounds.iter().map(|round| format!("{round}\n")).collect()
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct PlayersChoice {
     me: GameChoice,
@@ -55,15 +153,17 @@ impl PlayersChoice {
     }
 }
 
+impl From<Round> for PlayersChoice {
+    fn from(round: Round) -> Self {
+        PlayersChoice::new(round.response.as_choice(), round.opponent)
+    }
+}
+
 impl FromStr for PlayersChoice {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let choices = s.split_whitespace().collect::<Vec<&str>>();
-        Ok(PlayersChoice {
-            me: GameChoice::from_str(choices[1]).unwrap(),
-            opponent: GameChoice::from_str(choices[0]).unwrap(),
-        })
+        Round::from_str(s).map(PlayersChoice::from)
     }
 }
 
@@ -101,15 +201,20 @@ impl RoundPlan {
     }
 }
 
+impl From<Round> for RoundPlan {
+    fn from(round: Round) -> Self {
+        RoundPlan {
+            choice: round.opponent,
+            result: round.response.as_result(),
+        }
+    }
+}
+
 impl FromStr for RoundPlan {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let plan = s.split_whitespace().collect::<Vec<&str>>();
-        Ok(RoundPlan {
-            choice: GameChoice::from_str(plan[0]).unwrap(),
-            result: GameResult::from_str(plan[1]).unwrap(),
-        })
+        Round::from_str(s).map(RoundPlan::from)
     }
 }
 
@@ -118,8 +223,7 @@ fn predict_my_choice(plan: RoundPlan) -> GameChoice {
 
     *choices
         .iter()
-        .filter(|&&choice| GameResult::from(PlayersChoice::new(choice, plan.choice)) == plan.result)
-        .next()
+        .find(|&&choice| GameResult::from(PlayersChoice::new(choice, plan.choice)) == plan.result)
         .unwrap()
 }
 
@@ -127,54 +231,98 @@ fn calculate_round_score(result: GameResult, choice: GameChoice) -> u32 {
     result as u32 + choice as u32
 }
 
-fn main() {
-    let input = fs::read_to_string("./input").unwrap();
+/// Score of the round when the response is my choice (first part).
+fn score_by_choice(round: Round) -> u64 {
+    let choice = PlayersChoice::from(round);
+    calculate_round_score(GameResult::from(choice), choice.me).into()
+}
 
-    let rounds = input
-        .lines()
-        .map(|line| PlayersChoice::from_str(line).unwrap())
-        .collect::<Vec<PlayersChoice>>();
+/// Score of the round when the response is the expected result (second part).
+fn score_by_plan(round: Round) -> u64 {
+    let plan = RoundPlan::from(round);
+    calculate_round_score(plan.result, predict_my_choice(plan)).into()
+}
 
-    let results = rounds
-        .iter()
-        .map(|&round| GameResult::from(round))
-        .collect::<Vec<GameResult>>();
+pub struct Day02;
 
-    let total_score: u32 = rounds
-        .iter()
-        .zip(results.iter())
-        .map(|(&round, &result)| calculate_round_score(result, round.me))
-        .sum();
+impl Solution for Day02 {
+    const DAY: u8 = 2;
 
-    println!("Total score is {total_score}");
+    type Input = Vec<Round>;
 
-    let plans = input
-        .lines()
-        .map(|line| RoundPlan::from_str(line).unwrap())
-        .collect::<Vec<RoundPlan>>();
+    fn parse(input: &str) -> aoc_common::Result<Vec<Round>> {
+        parse_lines(input, Round::from_str)
+    }
 
-    let predicted_choices = plans
-        .iter()
-        .map(|&plan| predict_my_choice(plan))
-        .collect::<Vec<GameChoice>>();
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        check_lines(input, &mut report, Round::from_str);
+        report.into_diagnostics()
+    }
 
-    let predicted_total_score: u32 = plans
-        .iter()
-        .zip(predicted_choices.iter())
-        .map(|(&plan, &choice)| calculate_round_score(plan.result, choice))
-        .sum();
+    fn part1(rounds: &Vec<Round>) -> Answer {
+        rounds
+            .iter()
+            .map(|&round| score_by_choice(round))
+            .sum::<u64>()
+            .into()
+    }
 
-    println!("Predicted total score is {predicted_total_score}");
+    fn part2(rounds: &Vec<Round>) -> Answer {
+        rounds
+            .iter()
+            .map(|&round| score_by_plan(round))
+            .sum::<u64>()
+            .into()
+    }
+}
+
+impl StreamingSolution for Day02 {
+    fn solve_stream<R: BufRead>(reader: R) -> aoc_common::Result<[Answer; 2]> {
+        let (mut by_choice, mut by_plan) = (0u64, 0u64);
+        for round in stream_parsed_lines(reader, Round::from_str) {
+            let round = round?;
+            by_choice += score_by_choice(round);
+            by_plan += score_by_plan(round);
+        }
+        Ok([by_choice.into(), by_plan.into()])
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
     use crate::{
-        calculate_round_score, predict_my_choice, GameChoice, GameResult, PlayersChoice, RoundPlan,
+        calculate_round_score, predict_my_choice, serialize_strategy, Day02, GameChoice,
+        GameResult, PlayersChoice, Response, Round, RoundPlan,
     };
 
+    fn round() -> impl Strategy<Value = Round> {
+        let opponent = prop_oneof![
+            Just(GameChoice::Rock),
+            Just(GameChoice::Paper),
+            Just(GameChoice::Scissors)
+        ];
+        let response = prop_oneof![Just(Response::X), Just(Response::Y), Just(Response::Z)];
+        (opponent, response).prop_map(|(opponent, response)| Round { opponent, response })
+    }
+
+    proptest! {
+        #[test]
+        fn serialized_round_is_parsed_back(round in round()) {
+            prop_assert_eq!(Round::from_str(&round.to_string()), Ok(round));
+        }
+
+        #[test]
+        fn serialized_strategy_is_parsed_back(rounds in prop::collection::vec(round(), 0..50)) {
+            prop_assert_eq!(Day02::parse(&serialize_strategy(&rounds)).unwrap(), rounds);
+        }
+    }
+
     #[test]
     fn game_choice_is_correctly_parsed() {
         assert_eq!(GameChoice::from_str("X"), Ok(GameChoice::Rock));
@@ -212,6 +360,33 @@ mod tests {
             });
     }
 
+    #[test]
+    fn invalid_rounds_are_rejected() {
+        let test_inputs = ["A", "A X Y", "X X", "A Q", "D Z"];
+        let expected_errors = [
+            (
+                None,
+                "expected opponent's choice and response separated by a space",
+            ),
+            (
+                None,
+                "expected opponent's choice and response separated by a space",
+            ),
+            (Some(1), "unknown opponent's choice 'X', expected A, B or C"),
+            (Some(3), "unknown response 'Q', expected X, Y or Z"),
+            (Some(1), "unknown opponent's choice 'D', expected A, B or C"),
+        ];
+
+        test_inputs
+            .iter()
+            .zip(expected_errors.iter())
+            .for_each(|(&input, &(column, message))| {
+                let error = Round::from_str(input).unwrap_err();
+                assert_eq!(error.column, column);
+                assert_eq!(error.message, message);
+            });
+    }
+
     #[test]
     fn game_rules_are_correct() {
         let test_inputs = [
@@ -245,6 +420,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::identity_op)]
     fn scores_are_correctly_calculated() {
         let test_inputs = [
             PlayersChoice::new(GameChoice::Rock, GameChoice::Rock),
@@ -0,0 +1,388 @@
+use std::{collections::HashSet, fmt, io::BufRead, str::FromStr};
+
+use aoc_common::{
+    check::{Diagnostic, Report},
+    error::ParseError,
+    input::{numbered_lines, stream_lines},
+    Answer, Error, Solution, StreamingSolution,
+};
+
+// Every item type is identified by a single letter (case-sensitive)
+// Single line contains list of items in one rucksack
+// Every rucksack has 2 compartments, each containing the same amount of items
+// Each half of input line is list of items in each compartment
+// Every item type has priority, [a-z] => [1-26], [A-Z] => [27-52]
+
+type Item = u8;
+
+trait Weightable {
+    fn weight(&self) -> u8;
+}
+
+impl Weightable for Item {
+    fn weight(&self) -> u8 {
+        if self.is_ascii_lowercase() {
+            return self - b'a' + 1u8;
+        }
+        if self.is_ascii_uppercase() {
+            return self - b'A' + 27u8;
+        }
+        0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rucksack {
+    compartments: [HashSet<Item>; 2],
+}
+
+impl Rucksack {
+    fn shared_item(&self) -> Option<Item> {
+        self.compartments[0]
+            .intersection(&self.compartments[1])
+            .next()
+            .copied()
+    }
+
+    fn content(&self) -> HashSet<Item> {
+        let mut content = self.compartments[0].clone();
+        content.extend(self.compartments[1].iter());
+        content
+    }
+
+    fn find_badge(&self, second: &Rucksack, third: &Rucksack) -> Option<Item> {
+        let first = self.content();
+        let mut second = second.content();
+        let mut third = third.content();
+
+        let common: HashSet<Item> = first.iter().filter_map(|item| second.take(item)).collect();
+        common.iter().filter_map(|item| third.take(item)).next()
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(position) = s.bytes().position(|item| !item.is_ascii_alphabetic()) {
+            return Err(ParseError::new(format!(
+                "invalid item '{}', items must be ASCII letters",
+                s[position..].chars().next().unwrap_or_default()
+            ))
+            .at_column(position + 1));
+        }
+
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::new(format!(
+                "rucksack has an odd number of items ({}), compartments must be equal",
+                s.len()
+            )));
+        }
+
+        let item_count = s.len() / 2;
+        Ok(Rucksack {
+            compartments: [
+                HashSet::from_iter(s.as_bytes().get(0..item_count).unwrap().to_owned()),
+                HashSet::from_iter(
+                    s.as_bytes()
+                        .get(item_count..(item_count * 2))
+                        .unwrap()
+                        .to_owned(),
+                ),
+            ],
+        })
+    }
+}
+
+/// Writes the items in alphabetical order. Compartments only keep item types, so the smaller one
+/// is padded with copies of its last item - both halves of the line must have equal length.
+/// A rucksack with only one empty compartment has no textual form and is written as empty.
+impl fmt::Display for Rucksack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [first, second] = self.compartments.each_ref().map(|compartment| {
+            let mut items = compartment.iter().copied().collect::<Vec<Item>>();
+            items.sort_unstable();
+            items
+        });
+        let (Some(&first_last), Some(&second_last)) = (first.last(), second.last()) else {
+            return Ok(());
+        };
+
+        let length = first.len().max(second.len());
+        for (items, last) in [(first, first_last), (second, second_last)] {
+            let padding = std::iter::repeat_n(last, length - items.len());
+            for item in items.into_iter().chain(padding) {
+                write!(f, "{}", item as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a rucksack, which must have an item in both compartments.
+fn parse_rucksack(line: &str) -> Result<Rucksack, ParseError> {
+    let rucksack = Rucksack::from_str(line)?;
+    match rucksack.shared_item() {
+        Some(_) => Ok(rucksack),
+        None => Err(ParseError::new("compartments have no item in common")),
+    }
+}
+
+fn check_group_count(rucksack_count: usize) -> aoc_common::Result<()> {
+    if !rucksack_count.is_multiple_of(3) {
+        return Err(Error::parse(format!(
+            "there are {rucksack_count} rucksacks, but elves are split into groups of three"
+        )));
+    }
+    Ok(())
+}
+
+/// Badge of a group of three rucksacks, which are on lines `first` to `last`.
+fn group_badge(group: &[Rucksack], first: usize, last: usize) -> Result<Item, ParseError> {
+    match group {
+        [first, second, third] => first.find_badge(second, third),
+        _ => None,
+    }
+    .ok_or_else(|| ParseError::new(format!("group at lines {first}-{last} has no common badge")))
+}
+
+fn shared_item_weight(rucksack: &Rucksack) -> u64 {
+    rucksack
+        .shared_item()
+        .map_or(0, |item| item.weight().into())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> aoc_common::Result<Vec<Rucksack>> {
+        let mut rucksacks = vec![];
+        let mut group_start = 0;
+        for (number, line) in numbered_lines(input).filter(|(_, line)| !line.trim().is_empty()) {
+            rucksacks.push(parse_rucksack(line).map_err(|error| error.at_line(number, line))?);
+            match rucksacks.len() % 3 {
+                1 => group_start = number,
+                0 => {
+                    group_badge(&rucksacks[rucksacks.len() - 3..], group_start, number)?;
+                }
+                _ => {}
+            }
+        }
+        check_group_count(rucksacks.len())?;
+        Ok(rucksacks)
+    }
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        // invalid rucksacks still take their place in a group
+        let mut count = 0;
+        let mut group = Vec::with_capacity(3);
+        let mut group_start = 0;
+        for (number, line) in numbered_lines(input).filter(|(_, line)| !line.trim().is_empty()) {
+            count += 1;
+            if count % 3 == 1 {
+                group_start = number;
+            }
+            match parse_rucksack(line) {
+                Ok(rucksack) => group.push(rucksack),
+                Err(error) => report.error(error.at_line(number, line)),
+            }
+            if count % 3 == 0 {
+                // a group with an invalid rucksack has no badge to look for
+                if group.len() == 3 {
+                    if let Err(error) = group_badge(&group, group_start, number) {
+                        report.error(error);
+                    }
+                }
+                group.clear();
+            }
+        }
+        if let Err(error) = check_group_count(count) {
+            report.error(Diagnostic::from(error).problem);
+        }
+        report.into_diagnostics()
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> Answer {
+        rucksacks.iter().map(shared_item_weight).sum::<u64>().into()
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>) -> Answer {
+        find_badges(rucksacks)
+            .iter()
+            .flatten()
+            .map(|&b| u64::from(b.weight()))
+            .sum::<u64>()
+            .into()
+    }
+}
+
+impl StreamingSolution for Day03 {
+    fn solve_stream<R: BufRead>(reader: R) -> aoc_common::Result<[Answer; 2]> {
+        let (mut shared_items, mut badges) = (0u64, 0u64);
+        let mut count = 0;
+        // only the current group of three is kept
+        let mut group = Vec::with_capacity(3);
+        let mut group_start = 0;
+        for line in stream_lines(reader) {
+            let (number, line) = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let rucksack = parse_rucksack(&line).map_err(|error| error.at_line(number, &line))?;
+            count += 1;
+            if group.is_empty() {
+                group_start = number;
+            }
+            shared_items += shared_item_weight(&rucksack);
+            group.push(rucksack);
+            if group.len() == 3 {
+                badges += u64::from(group_badge(&group, group_start, number)?.weight());
+                group.clear();
+            }
+        }
+        check_group_count(count)?;
+
+        Ok([shared_items.into(), badges.into()])
+    }
+}
+
+fn find_badges(rucksacks: &[Rucksack]) -> Vec<Option<Item>> {
+    // no `array_chunks` on stable Rust, rip
+    rucksacks
+        .chunks_exact(3)
+        .map(|group| group[0].find_badge(&group[1], &group[2]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, str::FromStr};
+
+    use aoc_common::{Error, Solution};
+    use proptest::prelude::*;
+
+    use crate::{find_badges, Day03, Rucksack, Weightable};
+
+    fn compartment() -> impl Strategy<Value = HashSet<u8>> {
+        let item = prop_oneof![b'a'..=b'z', b'A'..=b'Z'];
+        prop::collection::hash_set(item, 1..=52)
+    }
+
+    fn rucksack() -> impl Strategy<Value = Rucksack> {
+        [compartment(), compartment()].prop_map(|compartments| Rucksack { compartments })
+    }
+
+    proptest! {
+        #[test]
+        fn serialized_rucksack_is_parsed_back(rucksack in rucksack()) {
+            let line = rucksack.to_string();
+            prop_assert_eq!(Rucksack::from_str(&line), Ok(rucksack));
+        }
+    }
+
+    #[test]
+    fn rucksack_is_serialized_with_equal_compartments() {
+        let rucksack = Rucksack::from_str("abcaXX").unwrap();
+        assert_eq!(rucksack.to_string(), "abcXaa");
+    }
+
+    #[test]
+    fn rucksack_is_correctly_created_from_str() {
+        let input = "qwertyuiopQWERTYUIOP";
+        let sack = Rucksack::from_str(input).unwrap();
+
+        assert_eq!(
+            sack.compartments[0],
+            HashSet::from_iter(b"qwertyuiop".to_owned().into_iter())
+        );
+        assert_eq!(
+            sack.compartments[1],
+            HashSet::from_iter(b"QWERTYUIOP".to_owned().into_iter())
+        );
+    }
+
+    #[test]
+    fn rucksack_detects_shared_element() {
+        let input = "qweAwE";
+        let sack = Rucksack::from_str(input).unwrap();
+
+        assert_eq!(sack.shared_item(), Some(b'w'));
+    }
+
+    #[test]
+    fn invalid_rucksacks_are_rejected() {
+        let odd = Rucksack::from_str("abcAB").err().unwrap();
+        assert_eq!(
+            odd.message,
+            "rucksack has an odd number of items (5), compartments must be equal"
+        );
+
+        let invalid_item = Rucksack::from_str("abc1AB").err().unwrap();
+        assert_eq!(invalid_item.column, Some(4));
+        assert_eq!(
+            invalid_item.message,
+            "invalid item '1', items must be ASCII letters"
+        );
+    }
+
+    #[test]
+    fn item_weight_is_correctly_calculated() {
+        let items = b"abcxyzABCXYZ";
+        let expected_weights = [
+            1u8, 2u8, 3u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8, 50u8, 51u8, 52u8,
+        ];
+
+        items
+            .iter()
+            .zip(expected_weights.iter())
+            .for_each(|(&item, &weight)| assert_eq!(item.weight(), weight));
+    }
+
+    #[test]
+    fn incomplete_groups_are_rejected() {
+        match Day03::parse("abcAbc\nabcAbc\nabcAbc\nabcAbc\n") {
+            Err(Error::Parse(error)) => assert_eq!(
+                error.message,
+                "there are 4 rucksacks, but elves are split into groups of three"
+            ),
+            other => panic!("expected parse error, got {:?}", other.map(|_| ())),
+        }
+
+        let rucksacks = ["abcAbc", "abcAbc"].map(|line| Rucksack::from_str(line).unwrap());
+        assert!(find_badges(&rucksacks).is_empty());
+    }
+
+    #[test]
+    fn finding_badges_works() {
+        let rucksacks = ["qwertyQWENTY", "asdfthASDFGH", "axcvtnZXCVBN"]
+            .map(|line| Rucksack::from_str(line).unwrap());
+
+        assert_eq!(
+            rucksacks[0].find_badge(&rucksacks[1], &rucksacks[2]),
+            Some(b't')
+        );
+    }
+
+    #[test]
+    fn groups_without_badge_are_rejected() {
+        let input = "aa\n\nbb\ncc\nxx\nxx\nxx\n";
+        match Day03::parse(input) {
+            Err(Error::Parse(error)) => {
+                assert_eq!(error.message, "group at lines 1-4 has no common badge")
+            }
+            other => panic!("expected parse error, got {:?}", other.map(|_| ())),
+        }
+
+        let diagnostics = Day03::check(input);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].problem.message,
+            "group at lines 1-4 has no common badge"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::{io::BufRead, ops::Range};
+
+use aoc_common::{
+    check::{Diagnostic, Report},
+    error::ParseError,
+    input::{numbered_lines, parse_lines, parse_token, stream_parsed_lines},
+    Answer, Solution, StreamingSolution,
+};
+
+fn ranges_overlap_fully<T>(first: &Range<T>, second: &Range<T>) -> bool
+where
+    T: PartialOrd,
+{
+    (first.start <= second.start && first.end >= second.end)
+        || (second.start <= first.start && second.end >= first.end)
+}
+
+fn ranges_overlap<T>(first: &Range<T>, second: &Range<T>) -> bool
+where
+    T: PartialOrd,
+{
+    (first.start <= second.end) && (second.start <= first.end)
+}
+
+pub type Sections = Range<i32>;
+pub type Pair = (Sections, Sections);
+
+fn parse_pair(line: &str, pair: &str) -> Result<Sections, ParseError> {
+    let pair_split = pair.split_once('-').ok_or_else(|| {
+        ParseError::new(format!("expected section range like '2-4', found '{pair}'"))
+            .at_token(line, pair)
+    })?;
+    let start = parse_token(line, pair_split.0, "first section number")?;
+    let end = parse_token(line, pair_split.1, "last section number")?;
+    Ok(start..end)
+}
+
+fn parse_line(line: &str) -> Result<Pair, ParseError> {
+    let pair_split = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new("expected two section ranges separated by a comma"))?;
+    Ok((
+        parse_pair(line, pair_split.0)?,
+        parse_pair(line, pair_split.1)?,
+    ))
+}
+
+/// Writes the pair back as a line of the assignment list, like `2-4,6-8`.
+pub fn serialize_pair((first, second): &Pair) -> String {
+    format!(
+        "{}-{},{}-{}",
+        first.start, first.end, second.start, second.end
+    )
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> aoc_common::Result<Vec<Pair>> {
+        parse_lines(input, parse_line)
+    }
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        for (number, line) in numbered_lines(input).filter(|(_, line)| !line.trim().is_empty()) {
+            let (first, second) = match parse_line(line) {
+                Ok(pair) => pair,
+                Err(error) => {
+                    report.error(error.at_line(number, line));
+                    continue;
+                }
+            };
+            let tokens = line.split_once(',').unwrap_or_default();
+            for (sections, token) in [(first, tokens.0), (second, tokens.1)] {
+                if sections.start > sections.end {
+                    report.warning(
+                        ParseError::new(format!(
+                            "section range '{token}' is reversed, the first section comes after the last one"
+                        ))
+                        .at_token(line, token)
+                        .at_line(number, line),
+                    );
+                }
+            }
+        }
+        report.into_diagnostics()
+    }
+
+    fn part1(pairs: &Vec<Pair>) -> Answer {
+        pairs
+            .iter()
+            .filter(|&(first, second)| ranges_overlap_fully(first, second))
+            .count()
+            .into()
+    }
+
+    fn part2(pairs: &Vec<Pair>) -> Answer {
+        pairs
+            .iter()
+            .filter(|&(first, second)| ranges_overlap(first, second))
+            .count()
+            .into()
+    }
+}
+
+impl StreamingSolution for Day04 {
+    fn solve_stream<R: BufRead>(reader: R) -> aoc_common::Result<[Answer; 2]> {
+        let (mut fully_overlapping, mut overlapping) = (0usize, 0usize);
+        for pair in stream_parsed_lines(reader, parse_line) {
+            let (first, second) = pair?;
+            fully_overlapping += usize::from(ranges_overlap_fully(&first, &second));
+            overlapping += usize::from(ranges_overlap(&first, &second));
+        }
+        Ok([fully_overlapping.into(), overlapping.into()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{error::ParseError, Solution};
+    use proptest::prelude::*;
+
+    use crate::{
+        parse_line, ranges_overlap, ranges_overlap_fully, serialize_pair, Day04, Pair, Sections,
+    };
+
+    fn sections() -> impl Strategy<Value = Sections> {
+        (1..100i32, 0..100i32).prop_map(|(start, length)| start..start + length)
+    }
+
+    fn pair() -> impl Strategy<Value = Pair> {
+        (sections(), sections())
+    }
+
+    proptest! {
+        #[test]
+        fn serialized_pair_is_parsed_back(pair in pair()) {
+            prop_assert_eq!(parse_line(&serialize_pair(&pair)), Ok(pair));
+        }
+
+        #[test]
+        fn serialized_assignments_are_parsed_back(pairs in prop::collection::vec(pair(), 0..50)) {
+            let input = pairs.iter().map(|pair| serialize_pair(pair) + "\n").collect::<String>();
+            prop_assert_eq!(Day04::parse(&input).unwrap(), pairs);
+        }
+    }
+
+    #[test]
+    fn ranges_overlap_fully_checks_overlap_correctly() {
+        let test_range = 0..5;
+        let fitting_ranges = [0..4, 1..5, 1..4, 0..5, 2..3, 4..4];
+        let overlapping_ranges = [-1..6, -1..5, 0..6, -5..10];
+        let non_overlapping_ranges = [1..10, -3..4, -10..2, 3..20];
+
+        for range in &fitting_ranges {
+            assert!(ranges_overlap_fully(&test_range, range));
+        }
+
+        for range in &overlapping_ranges {
+            assert!(ranges_overlap_fully(&test_range, range));
+        }
+
+        for range in &non_overlapping_ranges {
+            assert!(!ranges_overlap_fully(&test_range, range));
+        }
+    }
+
+    #[test]
+    fn ranges_overlap_checks_overlap_correctly() {
+        let test_range = 0..5;
+        let fully_overlapping_ranges = [
+            0..4,
+            1..5,
+            1..4,
+            0..5,
+            2..3,
+            4..4,
+            -1..6,
+            -1..5,
+            0..6,
+            -5..10,
+        ];
+        let partially_overlapping_ranges =
+            [-1..3, -8..1, -3..0, 2..10, 3..8, 4..9, 1..12, -4..3, 5..12];
+        let non_overlapping_ranges = [-4..-1, 8..10, -10..-2, 6..12];
+
+        for range in &fully_overlapping_ranges {
+            assert!(ranges_overlap(&test_range, range));
+        }
+
+        for range in &partially_overlapping_ranges {
+            assert!(ranges_overlap(&test_range, range));
+        }
+
+        for range in &non_overlapping_ranges {
+            assert!(!ranges_overlap(&test_range, range));
+        }
+    }
+
+    #[test]
+    fn lines_are_parsed_correctly() {
+        assert_eq!(parse_line("2-4,6-8"), Ok((2..4, 6..8)));
+        assert_eq!(parse_line("10-10,1-99"), Ok((10..10, 1..99)));
+    }
+
+    #[test]
+    fn invalid_lines_are_rejected() {
+        assert_eq!(
+            parse_line("2-4;6-8"),
+            Err(ParseError::new(
+                "expected two section ranges separated by a comma"
+            ))
+        );
+        assert_eq!(
+            parse_line("2-4,68"),
+            Err(ParseError::new("expected section range like '2-4', found '68'").at_column(5))
+        );
+        assert_eq!(
+            parse_line("2-4,6-x"),
+            Err(ParseError::new("expected last section number, found 'x'").at_column(7))
+        );
+    }
+}
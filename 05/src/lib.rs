@@ -0,0 +1,671 @@
+use std::fmt;
+
+use aoc_common::{
+    animation::{Animation, Frame},
+    check::{Diagnostic, Report},
+    error::ParseError,
+    input::{numbered_lines, parse_token},
+    Answer, Result, Solution,
+};
+use regex::Regex;
+use tracing::{debug, trace};
+
+pub type SupplyStorage = Vec<Vec<u8>>;
+
+/// Reads the drawing of crates, reporting every chunk that's not a crate.
+fn parse_supply_storage(lines: &[&str], report: &mut Report) -> SupplyStorage {
+    // columns have constant width, and there's always 4 characters per column (newline is omitted, hence +1)
+    let columns_amount = lines
+        .iter()
+        .map(|line| (line.len() + 1) / 4)
+        .max()
+        .unwrap_or(0);
+    let mut storage = vec![Vec::new(); columns_amount];
+
+    // so we can just parse every 4th character to check if there's a crate on stack, or not
+    for (number, line) in lines.iter().enumerate() {
+        for (column, crate_chunk) in line.as_bytes().chunks(4).enumerate() {
+            match crate_chunk {
+                [b'[', crate_name, b']'] | [b'[', crate_name, b']', b' ']
+                    if crate_name.is_ascii_alphabetic() =>
+                {
+                    storage[column].push(*crate_name)
+                }
+                _ if crate_chunk.iter().all(|&c| c == b' ') => {}
+                _ => report.error(
+                    ParseError::new("expected crate like '[A]' or empty space")
+                        .at_column(column * 4 + 1)
+                        .at_line(number + 1, line),
+                ),
+            }
+        }
+    }
+
+    // we put crates in reverse order in our storage, so we gotta flip it
+    for column in &mut storage {
+        column.reverse();
+    }
+
+    storage
+}
+
+/// Draws the storage the same way as the puzzle input, without stack numbers.
+pub fn draw_supply_storage(storage: &SupplyStorage) -> String {
+    let height = storage.iter().map(|column| column.len()).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for row in 0..height {
+        let required_length = height - row;
+        let line = storage
+            .iter()
+            .map(|column| match column.get(required_length - 1) {
+                Some(&item) => format!("[{}]", item as char),
+                None => String::from("   "),
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        drawing += line.trim_end();
+        drawing.push('\n');
+    }
+    drawing
+}
+
+/// Line with stack numbers, written under the drawing of the storage.
+fn stack_numbers(stack_count: usize) -> String {
+    (1..=stack_count)
+        .map(|number| format!(" {number} "))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Number of stacks, from the line under the drawing that numbers them 1, 2, 3...
+/// Stacks on the right may be empty, but every stack in the drawing must have a number.
+fn parse_stack_numbers(line: &str, drawn: usize) -> std::result::Result<usize, ParseError> {
+    let mut count = 0;
+    for token in line.split_whitespace() {
+        count += 1;
+        if token.parse::<usize>() != Ok(count) {
+            return Err(
+                ParseError::new(format!("expected stack number {count}, found '{token}'"))
+                    .at_token(line, token),
+            );
+        }
+    }
+    if count < drawn {
+        return Err(ParseError::new(format!(
+            "there are {drawn} stacks in the drawing, but only {count} of them are numbered"
+        )));
+    }
+    Ok(count)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CratesTransfer {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+// cranes can assume there are enough crates on the stack, `parse_procedure` checks that
+fn move_crates(storage: &mut SupplyStorage, transfer: &CratesTransfer) {
+    for _ in 0..transfer.amount {
+        let crate_name = storage[transfer.from].pop().unwrap();
+        storage[transfer.to].push(crate_name);
+    }
+}
+
+fn move_crates_all_at_once(storage: &mut SupplyStorage, transfer: &CratesTransfer) {
+    if transfer.from == transfer.to {
+        return;
+    }
+
+    let height = storage[transfer.from].len();
+    let remaining_height = height - transfer.amount;
+    for i in 0..transfer.amount {
+        let crate_name = storage[transfer.from][remaining_height + i];
+        storage[transfer.to].push(crate_name);
+    }
+    storage[transfer.from].truncate(remaining_height);
+}
+
+impl fmt::Display for CratesTransfer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// Starting crate arrangement together with the crane's rearrangement procedure.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Procedure {
+    storage: SupplyStorage,
+    transfers: Vec<CratesTransfer>,
+}
+
+/// Writes the procedure back in the puzzle input format.
+impl fmt::Display for Procedure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = stack_numbers(self.storage.len());
+        writeln!(f, "{}{numbers}\n", draw_supply_storage(&self.storage))?;
+        for transfer in &self.transfers {
+            writeln!(f, "{transfer}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Procedure> {
+        let mut report = Report::default();
+        let procedure = parse_procedure(input, &mut report);
+        report.into_result(procedure)
+    }
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        parse_procedure(input, &mut report);
+        report.into_diagnostics()
+    }
+
+    fn part1(procedure: &Procedure) -> Answer {
+        rearrange(procedure, move_crates).into()
+    }
+
+    fn part2(procedure: &Procedure) -> Answer {
+        rearrange(procedure, move_crates_all_at_once).into()
+    }
+
+    fn details(procedure: &Procedure) -> Option<String> {
+        Some(format!(
+            "starting arrangement:\n{}\nafter CrateMover 9000:\n{}\nafter CrateMover 9001:\n{}",
+            draw_supply_storage(&procedure.storage),
+            draw_supply_storage(&rearranged(procedure, move_crates)),
+            draw_supply_storage(&rearranged(procedure, move_crates_all_at_once)),
+        ))
+    }
+
+    fn animation(procedure: &Procedure) -> Option<Animation<'_>> {
+        Some(animate(procedure))
+    }
+}
+
+fn parse_transfer(
+    transfer_regex: &Regex,
+    line: &str,
+    stacks: usize,
+) -> std::result::Result<CratesTransfer, ParseError> {
+    let captures = transfer_regex
+        .captures(line)
+        .ok_or_else(|| ParseError::new("expected 'move <amount> from <stack> to <stack>'"))?;
+    let number = |index: usize, what: &str| parse_token::<usize>(line, &captures[index], what);
+    let stack = |index: usize| {
+        let stack = number(index, "stack number")?;
+        if (1..=stacks).contains(&stack) {
+            Ok(stack - 1)
+        } else {
+            Err(
+                ParseError::new(format!("there's no stack {stack}, expected 1 to {stacks}"))
+                    .at_token(line, &captures[index]),
+            )
+        }
+    };
+
+    Ok(CratesTransfer {
+        amount: number(1, "amount of crates")?,
+        from: stack(2)?,
+        to: stack(3)?,
+    })
+}
+
+/// Reads the procedure, reporting every problem with it, and carrying on after each one.
+/// Without a drawing, there's nothing to check the transfers against, and the procedure is empty.
+fn parse_procedure(input: &str, report: &mut Report) -> Procedure {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let Some(separator) = lines.iter().position(|line| line.trim().is_empty()) else {
+        report.error(ParseError::new(
+            "missing blank line between drawing and procedure",
+        ));
+        return Procedure::default();
+    };
+    if separator == 0 {
+        report.error(ParseError::new("missing crates drawing"));
+        return Procedure::default();
+    }
+
+    // last line of the drawing contains stack numbers, they tell how many stacks there are,
+    // as stacks on the right may be empty
+    let mut storage = parse_supply_storage(&lines[..separator - 1], report);
+    let numbers = lines[separator - 1];
+    let stacks = parse_stack_numbers(numbers, storage.len()).unwrap_or_else(|error| {
+        report.error(error.at_line(separator, numbers));
+        // transfers are still checked, against every stack there seems to be
+        numbers.split_whitespace().count()
+    });
+    if storage.len() < stacks {
+        storage.resize(stacks, vec![]);
+    }
+
+    // i've tested it. Manually. Once. Maybe twice. Trust me.
+    let transfer_regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    // both cranes leave the same number of crates on each stack, so it's enough to track heights
+    let mut heights = storage.iter().map(Vec::len).collect::<Vec<usize>>();
+    let mut transfers = vec![];
+    for (number, line) in numbered_lines(input).skip(separator + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let transfer = match parse_transfer(&transfer_regex, line, storage.len()) {
+            Ok(transfer) => transfer,
+            Err(error) => {
+                report.error(error.at_line(number, line));
+                continue;
+            }
+        };
+        // after moving too many crates, the rest is checked as if all of them were moved
+        let moved = heights[transfer.from].min(transfer.amount);
+        if moved < transfer.amount {
+            report.error(
+                ParseError::new(format!(
+                    "cannot move {} crates from stack {}, there are only {} left",
+                    transfer.amount,
+                    transfer.from + 1,
+                    heights[transfer.from]
+                ))
+                .at_line(number, line),
+            );
+        }
+        heights[transfer.from] -= moved;
+        heights[transfer.to] += moved;
+        transfers.push(transfer);
+    }
+
+    Procedure { storage, transfers }
+}
+
+fn top_crates(storage: &SupplyStorage) -> String {
+    storage
+        .iter()
+        .filter_map(|column| column.last())
+        .map(|&crate_name| crate_name as char)
+        .collect()
+}
+
+type Crane = fn(&mut SupplyStorage, &CratesTransfer);
+
+fn rearranged(procedure: &Procedure, crane: Crane) -> SupplyStorage {
+    let mut storage = procedure.storage.clone();
+    trace!("starting arrangement:\n{}", draw_supply_storage(&storage));
+    for transfer in &procedure.transfers {
+        crane(&mut storage, transfer);
+        debug!(
+            amount = transfer.amount,
+            from = transfer.from + 1,
+            to = transfer.to + 1,
+            top = %top_crates(&storage),
+            "moved crates"
+        );
+    }
+    trace!("final arrangement:\n{}", draw_supply_storage(&storage));
+    storage
+}
+
+fn rearrange(procedure: &Procedure, crane: Crane) -> String {
+    top_crates(&rearranged(procedure, crane))
+}
+
+/// Drawing of the storage with stack numbers, with crates moved by `transfer` highlighted.
+fn storage_frame(
+    caption: String,
+    storage: &SupplyStorage,
+    transfer: Option<&CratesTransfer>,
+) -> Frame {
+    let drawing = draw_supply_storage(storage) + &stack_numbers(storage.len());
+    let mut frame = Frame::new(caption, &drawing);
+    if let Some(transfer) = transfer {
+        // the first row of the drawing is the top of the highest stack
+        let height = storage.iter().map(|column| column.len()).max().unwrap_or(0);
+        let stack_height = storage[transfer.to].len();
+        let columns = transfer.to * 4..transfer.to * 4 + 3;
+        for level in stack_height - transfer.amount..stack_height {
+            frame = frame.highlight(height - 1 - level, columns.clone());
+        }
+    }
+    frame
+}
+
+/// Every transfer of both cranes, one after another.
+fn animate(procedure: &Procedure) -> Animation<'_> {
+    let cranes: [(&str, Crane); 2] = [
+        ("CrateMover 9000", move_crates),
+        ("CrateMover 9001", move_crates_all_at_once),
+    ];
+    let transfer_count = procedure.transfers.len();
+
+    Box::new(cranes.into_iter().flat_map(move |(name, crane)| {
+        let mut storage = procedure.storage.clone();
+        let start = storage_frame(format!("{name}: starting arrangement"), &storage, None);
+        let moves = procedure
+            .transfers
+            .iter()
+            .enumerate()
+            .map(move |(index, transfer)| {
+                crane(&mut storage, transfer);
+                let caption = format!(
+                    "{name}: {transfer} ({}/{transfer_count}), top crates: {}",
+                    index + 1,
+                    top_crates(&storage)
+                );
+                storage_frame(caption, &storage, Some(transfer))
+            });
+        std::iter::once(start).chain(moves)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        check::{Report, Severity},
+        error::ParseError,
+        Error, Solution,
+    };
+    use proptest::prelude::*;
+
+    use crate::{
+        draw_supply_storage, move_crates, move_crates_all_at_once, parse_supply_storage,
+        CratesTransfer, Day05, Procedure, SupplyStorage,
+    };
+
+    /// Storage from the drawing, or the first problem with it.
+    fn parse_drawing(drawing: &str) -> Result<SupplyStorage, ParseError> {
+        let mut report = Report::default();
+        let storage = parse_supply_storage(&drawing.lines().collect::<Vec<&str>>(), &mut report);
+        match report.into_diagnostics().into_iter().next() {
+            Some(diagnostic) => Err(diagnostic.problem),
+            None => Ok(storage),
+        }
+    }
+
+    fn supply_storage() -> impl Strategy<Value = SupplyStorage> {
+        let stack = prop::collection::vec(b'A'..=b'Z', 0..8);
+        prop::collection::vec(stack, 1..=9)
+    }
+
+    fn procedure() -> impl Strategy<Value = Procedure> {
+        supply_storage().prop_flat_map(|storage| {
+            let stacks = storage.len();
+            let transfer = (0..10usize, 0..stacks, 0..stacks);
+            prop::collection::vec(transfer, 0..20).prop_map(move |transfers| {
+                // crane can't take more crates than there are on the stack
+                let mut heights = storage.iter().map(Vec::len).collect::<Vec<usize>>();
+                let transfers = transfers
+                    .into_iter()
+                    .map(|(amount, from, to)| {
+                        let amount = amount.min(heights[from]);
+                        heights[from] -= amount;
+                        heights[to] += amount;
+                        CratesTransfer { amount, from, to }
+                    })
+                    .collect();
+                Procedure {
+                    storage: storage.clone(),
+                    transfers,
+                }
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn drawn_supply_storage_is_parsed_back(storage in supply_storage()) {
+            let drawing = draw_supply_storage(&storage);
+            let parsed = parse_drawing(&drawing).unwrap();
+            // empty stacks on the right aren't visible in the drawing
+            prop_assert_eq!(&parsed[..], &storage[..parsed.len()]);
+            prop_assert!(storage[parsed.len()..].iter().all(Vec::is_empty));
+        }
+
+        #[test]
+        fn serialized_procedure_is_parsed_back(procedure in procedure()) {
+            prop_assert_eq!(Day05::parse(&procedure.to_string()).unwrap(), procedure);
+        }
+    }
+
+    #[test]
+    fn procedure_is_serialized_like_input() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+        assert_eq!(Day05::parse(input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn moved_crates_are_highlighted_in_animation() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 2 from 1 to 3\n";
+        let procedure = Day05::parse(input).unwrap();
+        let frames = Day05::animation(&procedure).unwrap().collect::<Vec<_>>();
+
+        // starting arrangement and both moves, for each crane
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[0].caption, "CrateMover 9000: starting arrangement");
+        assert!(frames[0].highlights.is_empty());
+
+        let second_move = &frames[2];
+        assert_eq!(
+            second_move.caption,
+            "CrateMover 9000: move 2 from 1 to 3 (2/2), top crates: ZCN"
+        );
+        assert_eq!(
+            second_move.lines,
+            vec!["        [N]", "    [C] [D]", "[Z] [M] [P]", " 1   2   3 "]
+        );
+        let highlighted = (0..second_move.lines.len())
+            .filter(|&line| second_move.is_highlighted(line, 8))
+            .collect::<Vec<_>>();
+        assert_eq!(highlighted, vec![0, 1]);
+        assert_eq!(
+            frames[5].caption,
+            "CrateMover 9001: move 2 from 1 to 3 (2/2), top crates: ZCD"
+        );
+    }
+
+    #[test]
+    fn supply_is_parsed_correctly() {
+        let input_a = "[A]     [B]     [C]\n[D] [E] [F] [G] [H]\n";
+        let expected_a: SupplyStorage = [
+            [b'D', b'A'].to_vec(),
+            [b'E'].to_vec(),
+            [b'F', b'B'].to_vec(),
+            [b'G'].to_vec(),
+            [b'H', b'C'].to_vec(),
+        ]
+        .to_vec();
+
+        assert_eq!(parse_drawing(input_a), Ok(expected_a));
+    }
+
+    #[test]
+    fn supply_is_drawn_like_input() {
+        let drawing = "[A]     [B]     [C]\n[D] [E] [F] [G] [H]\n";
+        let storage = parse_drawing(drawing).unwrap();
+
+        assert_eq!(draw_supply_storage(&storage), drawing);
+        assert_eq!(draw_supply_storage(&vec![vec![]; 3]), "");
+    }
+
+    #[test]
+    fn crates_are_transferred_correctly() {
+        let mut storage: SupplyStorage = [
+            [b'D', b'A'].to_vec(),
+            [b'E'].to_vec(),
+            [b'F', b'B'].to_vec(),
+            [b'G'].to_vec(),
+            [b'H', b'C'].to_vec(),
+        ]
+        .to_vec();
+
+        let transfer = CratesTransfer {
+            amount: 2,
+            from: 2,
+            to: 0,
+        };
+
+        let expected_storage: SupplyStorage = [
+            [b'D', b'A', b'B', b'F'].to_vec(),
+            [b'E'].to_vec(),
+            [].to_vec(),
+            [b'G'].to_vec(),
+            [b'H', b'C'].to_vec(),
+        ]
+        .to_vec();
+
+        move_crates(&mut storage, &transfer);
+        assert_eq!(storage, expected_storage);
+    }
+
+    #[test]
+    fn crates_are_transferred_correctly_all_at_once() {
+        let mut storage: SupplyStorage = [
+            [b'D', b'A'].to_vec(),
+            [b'E'].to_vec(),
+            [b'F', b'B'].to_vec(),
+            [b'G'].to_vec(),
+            [b'H', b'C'].to_vec(),
+        ]
+        .to_vec();
+
+        let transfer = CratesTransfer {
+            amount: 2,
+            from: 2,
+            to: 0,
+        };
+
+        let expected_storage: SupplyStorage = [
+            [b'D', b'A', b'F', b'B'].to_vec(),
+            [b'E'].to_vec(),
+            [].to_vec(),
+            [b'G'].to_vec(),
+            [b'H', b'C'].to_vec(),
+        ]
+        .to_vec();
+
+        move_crates_all_at_once(&mut storage, &transfer);
+        assert_eq!(storage, expected_storage);
+    }
+
+    #[test]
+    fn crates_moved_onto_same_stack_stay_there() {
+        let mut storage: SupplyStorage = [[b'A', b'B'].to_vec()].to_vec();
+        let transfer = CratesTransfer {
+            amount: 2,
+            from: 0,
+            to: 0,
+        };
+
+        move_crates(&mut storage, &transfer);
+        move_crates_all_at_once(&mut storage, &transfer);
+        assert_eq!(storage, [[b'A', b'B'].to_vec()].to_vec());
+    }
+
+    #[test]
+    fn invalid_input_is_reported_with_location() {
+        let invalid_inputs = [
+            "[A] [B]\n[C] (D)\n 1   2\n\nmove 1 from 1 to 2\n",
+            "[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove one from 1 to 2\n",
+            "[A] [B]\n 1   2\n\nmove 1 from 1 to 3\n",
+            "[A] [B]\n 1   2\nmove 1 from 1 to 2\n",
+            "[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 1 to 2\n",
+            "    [D]\n[N] [C]\n 1 2 3 x\n\nmove 1 from 2 to 1\n",
+            "[A] [B]\n 1   3\n\nmove 1 from 1 to 2\n",
+            "[A] [B] [C]\n 1   2\n\nmove 1 from 1 to 2\n",
+        ];
+
+        let expected_errors = [
+            (Some(2), Some(5), "expected crate like '[A]' or empty space"),
+            (
+                Some(5),
+                None,
+                "expected 'move <amount> from <stack> to <stack>'",
+            ),
+            (Some(4), Some(18), "there's no stack 3, expected 1 to 2"),
+            (
+                None,
+                None,
+                "missing blank line between drawing and procedure",
+            ),
+            (
+                Some(5),
+                None,
+                "cannot move 2 crates from stack 1, there are only 0 left",
+            ),
+            (Some(3), Some(8), "expected stack number 4, found 'x'"),
+            (Some(2), Some(6), "expected stack number 2, found '3'"),
+            (
+                Some(2),
+                None,
+                "there are 3 stacks in the drawing, but only 2 of them are numbered",
+            ),
+        ];
+
+        invalid_inputs.iter().zip(expected_errors.iter()).for_each(
+            |(input, &(line, column, message))| match Day05::parse(input) {
+                Err(Error::Parse(error)) => {
+                    assert_eq!((error.line, error.column), (line, column));
+                    assert_eq!(error.message, message);
+                }
+                _ => panic!("input should be rejected: {input:?}"),
+            },
+        );
+    }
+
+    #[test]
+    fn check_reports_every_problem() {
+        let input = "[A] (B)
+ 1   2
+
+move 1 from 2 to 1
+move x from 1 to 2
+move 5 from 1 to 2
+move 1 from 1 to 3
+move 1 from 2 to 1
+";
+        let problems = Day05::check(input)
+            .into_iter()
+            .map(|diagnostic| {
+                assert_eq!(diagnostic.severity, Severity::Error);
+                (diagnostic.problem.line, diagnostic.problem.message)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    Some(1),
+                    String::from("expected crate like '[A]' or empty space")
+                ),
+                (
+                    Some(4),
+                    String::from("cannot move 1 crates from stack 2, there are only 0 left")
+                ),
+                (
+                    Some(5),
+                    String::from("expected 'move <amount> from <stack> to <stack>'")
+                ),
+                (
+                    Some(6),
+                    String::from("cannot move 5 crates from stack 1, there are only 1 left")
+                ),
+                (Some(7), String::from("there's no stack 3, expected 1 to 2")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io::BufRead,
+};
+
+use aoc_common::{
+    animation::{Animation, Frame},
+    check::{Diagnostic, Report},
+    error::ParseError,
+    input::{numbered_lines, stream_bytes},
+    Answer, Result, Solution, StreamingSolution,
+};
+use tracing::debug;
+
+/// Every window of `length` characters the marker is looked for in, in order, with the number
+/// of characters up to its end and whether all characters in it are different.
+fn marker_candidates(data: &str, length: usize) -> impl Iterator<Item = (usize, &[u8], bool)> {
+    data.as_bytes()
+        .windows(length)
+        .enumerate()
+        .map(move |(index, window)| {
+            // could use `.unique()` from itertools here to make it one-liner, but w/e
+            let set: HashSet<u8> = HashSet::from_iter(window.iter().cloned());
+            (index + length, window, set.len() == window.len())
+        })
+}
+
+fn find_start_marker_index(data: &str, length: usize) -> usize {
+    marker_candidates(data, length)
+        .inspect(|&(index, window, unique)| {
+            debug!(
+                index,
+                window = %String::from_utf8_lossy(window),
+                unique,
+                "marker candidate"
+            )
+        })
+        .find(|&(_, _, unique)| unique)
+        .map_or(0, |(index, _, _)| index)
+}
+
+/// Characters shown around the window in the animation.
+const VIEW_MARGIN: usize = 30;
+
+/// Part of the datastream around the window ending at `end`, with the window highlighted.
+fn window_frame(marker: &str, data: &[u8], end: usize, length: usize, unique: bool) -> Frame {
+    let start = end - length;
+    let view_start = start.saturating_sub(VIEW_MARGIN);
+    let view_end = (end + VIEW_MARGIN).min(data.len());
+    // one character per byte, so the highlight stays in place
+    let view = data[view_start..view_end]
+        .iter()
+        .map(|&byte| match byte {
+            b' ' | b'!'..=b'~' => byte as char,
+            _ => '?',
+        })
+        .collect::<String>();
+    let (before, after) = (
+        if view_start > 0 { "..." } else { "" },
+        if view_end < data.len() { "..." } else { "" },
+    );
+    let verdict = if unique {
+        format!("all different, the marker ends after character {end}")
+    } else {
+        String::from("some characters repeat")
+    };
+
+    let highlight_start = before.len() + start - view_start;
+    Frame::new(
+        format!("{marker} marker, characters {}-{end}: {verdict}", start + 1),
+        &format!("{before}{view}{after}"),
+    )
+    .highlight(0, highlight_start..highlight_start + length)
+}
+
+/// Window sliding over the datastream until it finds each of the markers.
+fn animate(datastream: &str) -> Animation<'_> {
+    let markers = [("start-of-packet", 4), ("start-of-message", 14)];
+    Box::new(markers.into_iter().flat_map(move |(marker, length)| {
+        marker_candidates(datastream, length)
+            .scan(false, |found, (end, _, unique)| {
+                (!*found).then(|| {
+                    *found = unique;
+                    (end, unique)
+                })
+            })
+            .map(move |(end, unique)| {
+                window_frame(marker, datastream.as_bytes(), end, length, unique)
+            })
+    }))
+}
+
+/// Whitespace trimmed from both ends of the datastream. Only ASCII, so a stream of bytes can be
+/// trimmed the same way.
+fn is_blank(byte: u8) -> bool {
+    byte.is_ascii() && char::from(byte).is_whitespace()
+}
+
+/// Looks for the start marker in a stream of bytes, remembering only the last `length` of them.
+struct MarkerFinder {
+    length: usize,
+    window: VecDeque<u8>,
+    counts: [usize; 256],
+    repeated: usize,
+    found: Option<usize>,
+    /// Marker ending with whitespace, it counts only if something else comes after it
+    pending: Option<usize>,
+}
+
+impl MarkerFinder {
+    fn new(length: usize) -> Self {
+        MarkerFinder {
+            length,
+            window: VecDeque::with_capacity(length + 1),
+            counts: [0; 256],
+            repeated: 0,
+            found: None,
+            pending: None,
+        }
+    }
+
+    /// Takes the byte at 1-based `position` of the datastream.
+    fn push(&mut self, position: usize, byte: u8) {
+        if self.found.is_some() {
+            return;
+        }
+        if !is_blank(byte) && self.pending.is_some() {
+            self.found = self.pending;
+            return;
+        }
+
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 2 {
+            self.repeated += 1;
+        }
+        self.window.push_back(byte);
+        if self.window.len() > self.length {
+            let oldest = self.window.pop_front().unwrap_or_default();
+            self.counts[oldest as usize] -= 1;
+            if self.counts[oldest as usize] == 1 {
+                self.repeated -= 1;
+            }
+        }
+
+        if self.window.len() == self.length && self.repeated == 0 {
+            if !is_blank(byte) {
+                self.found = Some(position);
+            } else if self.pending.is_none() {
+                self.pending = Some(position);
+            }
+        }
+    }
+
+    fn index(&self) -> usize {
+        self.found.unwrap_or(0)
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input
+            .trim_matches(|c: char| c.is_ascii() && is_blank(c as u8))
+            .to_string())
+    }
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        // anything is accepted, but the device only sends lowercase letters
+        for (number, line) in numbered_lines(input) {
+            let data = line.trim_matches(|c: char| c.is_ascii() && is_blank(c as u8));
+            let mut unexpected = data.char_indices().filter(|(_, c)| !c.is_ascii_lowercase());
+            if let Some((index, character)) = unexpected.next() {
+                report.warning(
+                    ParseError::new(format!(
+                        "'{}' is not a lowercase letter ({} such characters in the line)",
+                        character.escape_debug(),
+                        unexpected.count() + 1
+                    ))
+                    .at_token(line, &data[index..])
+                    .at_line(number, line),
+                );
+            }
+        }
+
+        let datastream = Self::parse(input).unwrap_or_default();
+        for (marker, length) in [("start-of-packet", 4), ("start-of-message", 14)] {
+            if !marker_candidates(&datastream, length).any(|(_, _, unique)| unique) {
+                report.warning(ParseError::new(format!(
+                    "there's no {marker} marker, the answer is 0"
+                )));
+            }
+        }
+        report.into_diagnostics()
+    }
+
+    fn part1(datastream: &String) -> Answer {
+        find_start_marker_index(datastream, 4).into()
+    }
+
+    fn part2(datastream: &String) -> Answer {
+        find_start_marker_index(datastream, 14).into()
+    }
+
+    fn animation(datastream: &String) -> Option<Animation<'_>> {
+        Some(animate(datastream))
+    }
+}
+
+impl StreamingSolution for Day06 {
+    fn solve_stream<R: BufRead>(reader: R) -> Result<[Answer; 2]> {
+        let mut finders = [MarkerFinder::new(4), MarkerFinder::new(14)];
+        let mut position = 0;
+        stream_bytes(reader, |byte| {
+            if position == 0 && is_blank(byte) {
+                return;
+            }
+            position += 1;
+            for finder in &mut finders {
+                finder.push(position, byte);
+            }
+        })?;
+
+        Ok(finders.map(|finder| finder.index().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Solution, StreamingSolution};
+
+    use crate::{find_start_marker_index, Day06, VIEW_MARGIN};
+
+    #[test]
+    fn finds_start_marker_index_correctly() {
+        let input = "qweqaweqddss";
+        let expected_marker_index = 5usize;
+        assert_eq!(find_start_marker_index(input, 4), expected_marker_index);
+    }
+
+    #[test]
+    fn streamed_markers_ignore_surrounding_whitespace() {
+        let inputs = [
+            "  abcd\n",
+            "abc \n",
+            "abc \nd",
+            "ab\x0b\tc",
+            "\t\nab cab",
+            "aaaa",
+        ];
+
+        for input in inputs {
+            let datastream = Day06::parse(input).unwrap();
+            let in_memory = [Day06::part1(&datastream), Day06::part2(&datastream)];
+            let streamed = Day06::solve_stream(input.as_bytes()).unwrap();
+            assert_eq!(streamed, in_memory, "input {input:?}");
+        }
+    }
+
+    #[test]
+    fn window_slides_until_markers_are_found() {
+        let datastream = Day06::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+        let frames = Day06::animation(&datastream).unwrap().collect::<Vec<_>>();
+
+        // marker of 4 characters ends at 7, the one of 14 at 19
+        assert_eq!(frames.len(), 4 + 6);
+        assert_eq!(
+            frames[0].caption,
+            "start-of-packet marker, characters 1-4: some characters repeat"
+        );
+        assert_eq!(
+            frames[3].caption,
+            "start-of-packet marker, characters 4-7: all different, the marker ends after character 7"
+        );
+        assert_eq!(frames[3].lines, vec![datastream.clone()]);
+        assert!((3..7).all(|column| frames[3].is_highlighted(0, column)));
+        assert!(!frames[3].is_highlighted(0, 7));
+        assert!(frames[9].caption.ends_with("ends after character 19"));
+    }
+
+    #[test]
+    fn long_datastreams_are_shown_around_the_window() {
+        let datastream = "ab".repeat(50) + "cdef";
+        let frames = Day06::animation(&datastream).unwrap().collect::<Vec<_>>();
+        let last = &frames[frames.len() - 1];
+
+        assert!(last.lines[0].starts_with("..."));
+        assert_eq!(last.lines[0].len(), 3 + VIEW_MARGIN + 14);
+        assert!(last.is_highlighted(0, 3 + VIEW_MARGIN));
+    }
+}
@@ -0,0 +1,521 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use aoc_common::{
+    check::{Diagnostic, Report},
+    error::ParseError,
+    input::numbered_lines,
+    Answer, Solution,
+};
+use tracing::debug;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Node {
+    name: String,
+    size: Option<usize>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn file(name: impl Into<String>, size: usize) -> Self {
+        Node {
+            name: name.into(),
+            size: Some(size),
+            children: vec![],
+        }
+    }
+
+    fn directory(name: impl Into<String>) -> Self {
+        Node {
+            name: name.into(),
+            size: None,
+            children: vec![],
+        }
+    }
+
+    fn is_directory(&self) -> bool {
+        self.size.is_none()
+    }
+
+    /// Total size, or `None` if it doesn't fit in `usize`.
+    fn checked_total_size(&self) -> Option<usize> {
+        match self.size {
+            Some(size) => Some(size),
+            None => self.children.iter().try_fold(0usize, |sum, child| {
+                sum.checked_add(child.checked_total_size()?)
+            }),
+        }
+    }
+
+    /// Parsed trees are checked to not overflow, see `parse_input`.
+    fn total_size(&self) -> usize {
+        self.size
+            .unwrap_or_else(|| self.children.iter().map(Node::total_size).sum())
+    }
+
+    /// Total sizes of this directory and all directories below it.
+    fn directory_sizes(&self) -> Vec<usize> {
+        if !self.is_directory() {
+            return vec![];
+        }
+
+        let mut sizes = vec![self.total_size()];
+        for child in &self.children {
+            sizes.extend(child.directory_sizes());
+        }
+        sizes
+    }
+
+    /// Terminal session that explores the whole tree, as in the puzzle input.
+    /// The node is treated as the root directory.
+    pub fn terminal_output(&self) -> String {
+        let mut output = String::from("$ cd /\n");
+        self.write_listing(&mut output);
+        output
+    }
+
+    fn write_listing(&self, output: &mut String) {
+        output.push_str("$ ls\n");
+        for child in &self.children {
+            match child.size {
+                Some(size) => output.push_str(&format!("{size} {}\n", child.name)),
+                None => output.push_str(&format!("dir {}\n", child.name)),
+            }
+        }
+
+        for child in self.children.iter().filter(|child| child.is_directory()) {
+            output.push_str(&format!("$ cd {}\n", child.name));
+            child.write_listing(output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+}
+
+impl TryFrom<&str> for Node {
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let split_line = line.split_whitespace().collect::<Vec<&str>>();
+        if split_line.len() < 2 {
+            return Err(ParseError::new("Invalid input - no whitespace detected"));
+        }
+
+        let name = split_line[1];
+        // names have no spaces, anything after the name is a mistake
+        if let Some(extra) = split_line.get(2) {
+            return Err(
+                ParseError::new("Invalid input - unexpected text after the name")
+                    .at_token(line, extra),
+            );
+        }
+
+        if split_line[0] == "dir" {
+            return Ok(Node::directory(name));
+        }
+
+        if let Ok(file_size) = split_line[0].parse::<usize>() {
+            return Ok(Node::file(name, file_size));
+        }
+
+        Err(ParseError::new("Invalid input - unknown node type").at_token(line, split_line[0]))
+    }
+
+    type Error = ParseError;
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Command {
+    ChangeDirectory(String),
+    ListDirectory,
+}
+
+fn parse_command(line: &str) -> Result<Command, ParseError> {
+    if let Some(name) = line.strip_prefix("$ cd ") {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ParseError::new("Missing directory name").at_column(line.len() + 1));
+        }
+        return Ok(Command::ChangeDirectory(name.to_string()));
+    }
+
+    if line.trim_end() == "$ ls" {
+        return Ok(Command::ListDirectory);
+    }
+
+    let column = if line.starts_with("$ ") { 3 } else { 1 };
+    Err(ParseError::new("Unknown command").at_column(column))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum InputLine {
+    Node(Node),
+    Command(Command),
+}
+
+fn parse_input_line(line: &str) -> Result<InputLine, ParseError> {
+    if line.starts_with("$ ") {
+        return parse_command(line).map(InputLine::Command);
+    }
+
+    Node::try_from(line).map(InputLine::Node)
+}
+
+const DISK_SIZE: usize = 70000000;
+const REQUIRED_FREE_SPACE: usize = 30000000;
+const SMALL_DIRECTORY_SIZE: usize = 100000;
+
+fn parse_input(input: &str) -> aoc_common::Result<Node> {
+    let mut report = Report::default();
+    let root = parse_terminal_output(input, &mut report);
+    report.into_result(root)
+}
+
+/// Node listed in a directory, as remembered while the tree is read.
+#[derive(Debug, Clone, Copy)]
+struct Listed {
+    /// Position among children of the directory
+    index: usize,
+    size: Option<usize>,
+    /// Id of the node if it's a directory, to find nodes listed in it
+    directory: Option<usize>,
+}
+
+/// Tree being read from the terminal session. Listed nodes are found through a map, and
+/// the working directory is a path of positions among children, so neither moving around
+/// nor listing depends on the number of siblings.
+struct TreeBuilder<'a> {
+    root: Node,
+    /// Nodes listed in every directory by their names, directories are indexed by their ids
+    /// and root's id is 0
+    listed: Vec<HashMap<&'a str, Listed>>,
+    /// Position among children, id and name of the working directory and every one
+    /// above it, except root
+    cwd: Vec<(usize, usize, String)>,
+    /// Nodes listed in the working directory, not added to the tree yet
+    pending: Vec<Node>,
+}
+
+impl<'a> TreeBuilder<'a> {
+    fn new() -> Self {
+        TreeBuilder {
+            root: Node::directory("/"),
+            listed: vec![HashMap::new()],
+            cwd: vec![],
+            pending: vec![],
+        }
+    }
+
+    fn cwd_id(&self) -> usize {
+        self.cwd.last().map_or(0, |&(_, id, _)| id)
+    }
+
+    fn path(&self) -> String {
+        let names = self
+            .cwd
+            .iter()
+            .map(|(_, _, name)| name.as_str())
+            .collect::<Vec<&str>>();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Adds `node` named `name` to the working directory. If a node was listed there
+    /// before under the same name, nothing is added and that node is returned.
+    fn list(&mut self, name: &'a str, node: Node) -> Result<(), Listed> {
+        let directory = node.is_directory().then_some(self.listed.len());
+        let parent = self.cwd_id();
+        let listed = &mut self.listed[parent];
+        let index = listed.len();
+        match listed.entry(name) {
+            Entry::Occupied(existing) => return Err(*existing.get()),
+            Entry::Vacant(entry) => entry.insert(Listed {
+                index,
+                size: node.size,
+                directory,
+            }),
+        };
+        if directory.is_some() {
+            self.listed.push(HashMap::new());
+        }
+        self.pending.push(node);
+        Ok(())
+    }
+
+    /// Moves the nodes listed so far to the working directory, walking to it once.
+    fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let mut directory = &mut self.root;
+        for &(index, _, _) in &self.cwd {
+            directory = &mut directory.children[index];
+        }
+        directory.children.append(&mut self.pending);
+    }
+
+    /// Enters the directory named `name`, `false` if there's no such directory.
+    fn change_directory(&mut self, name: &str) -> bool {
+        self.flush();
+        match name {
+            "/" => self.cwd.clear(),
+            ".." => {
+                self.cwd.pop();
+            }
+            _ => match self.listed[self.cwd_id()].get(name) {
+                Some(&Listed {
+                    index,
+                    directory: Some(id),
+                    ..
+                }) => self.cwd.push((index, id, name.to_string())),
+                _ => return false,
+            },
+        }
+        true
+    }
+
+    fn build(mut self) -> Node {
+        self.flush();
+        self.root
+    }
+}
+
+/// Builds the tree from the terminal session, reporting every invalid line and skipping it.
+/// Listing a directory again is fine, but a name listed before must be the same file or directory.
+fn parse_terminal_output(input: &str, report: &mut Report) -> Node {
+    let mut tree = TreeBuilder::new();
+
+    for (number, line) in numbered_lines(input).filter(|(_, line)| !line.trim().is_empty()) {
+        let input_line = match parse_input_line(line) {
+            Ok(input_line) => input_line,
+            Err(error) => {
+                report.error(error.at_line(number, line));
+                continue;
+            }
+        };
+
+        match input_line {
+            InputLine::Node(node) => {
+                // the name is the second token, as in `Node::try_from`
+                let name = line.split_whitespace().nth(1).unwrap_or_default();
+                let size = node.size;
+                match tree.list(name, node) {
+                    Err(existing) if existing.size != size => report.error(
+                        ParseError::new(format!(
+                            "'{name}' in {} was listed before as something else",
+                            tree.path()
+                        ))
+                        .at_line(number, line),
+                    ),
+                    _ => {}
+                }
+            }
+            InputLine::Command(command) => match command {
+                Command::ChangeDirectory(new_cwd_name) => {
+                    // the working directory stays where it was
+                    if !tree.change_directory(&new_cwd_name) {
+                        report.error(
+                            ParseError::new(format!(
+                                "no directory named '{new_cwd_name}' in {}",
+                                tree.path()
+                            ))
+                            .at_column(6)
+                            .at_line(number, line),
+                        );
+                    } else {
+                        debug!(line = number, "entered {}", tree.path());
+                    }
+                }
+                Command::ListDirectory => {}
+            },
+        }
+    }
+
+    let root = tree.build();
+    // sizes of all directories are at most the total, so it's the only one that needs checking
+    if root.checked_total_size().is_none() {
+        report.error(ParseError::new("total size of all files is too large"));
+    }
+
+    root
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Node;
+
+    fn parse(input: &str) -> aoc_common::Result<Node> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        parse_terminal_output(input, &mut report);
+        report.into_diagnostics()
+    }
+
+    fn part1(root: &Node) -> Answer {
+        root.directory_sizes()
+            .into_iter()
+            .filter(|&size| size <= SMALL_DIRECTORY_SIZE)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(root: &Node) -> Answer {
+        let free_space = DISK_SIZE.saturating_sub(root.total_size());
+        let missing_space = REQUIRED_FREE_SPACE.saturating_sub(free_space);
+        root.directory_sizes()
+            .into_iter()
+            .filter(|&size| size >= missing_space)
+            .min()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::Error;
+    use proptest::prelude::*;
+
+    use crate::{parse_command, parse_input, Command, Node};
+
+    /// Directory with given children, skipping the ones with repeated names.
+    fn directory_with(name: String, children: Vec<Node>) -> Node {
+        let mut names = HashSet::new();
+        let mut directory = Node::directory(name);
+        directory.children = children
+            .into_iter()
+            .filter(|child| names.insert(child.name.clone()))
+            .collect();
+        directory
+    }
+
+    fn tree() -> impl Strategy<Value = Node> {
+        let name = || "[a-z]{1,8}(\\.[a-z]{1,3})?";
+        let file = (name(), 1..1_000_000usize).prop_map(|(name, size)| Node::file(name, size));
+        let node = file.prop_recursive(4, 64, 8, move |node| {
+            (name(), prop::collection::vec(node, 0..8))
+                .prop_map(|(name, children)| directory_with(name, children))
+        });
+        prop::collection::vec(node, 0..8)
+            .prop_map(|children| directory_with(String::from("/"), children))
+    }
+
+    proptest! {
+        #[test]
+        fn terminal_output_is_parsed_back(root in tree()) {
+            prop_assert_eq!(parse_input(&root.terminal_output()).unwrap(), root);
+        }
+    }
+
+    #[test]
+    fn test_node_parsing() {
+        let inputs = [
+            "dir qwe",
+            "dir asdASD",
+            "ddddir qweqwe",
+            "qweqweasdad",
+            "1234 test",
+            "2356 example",
+            "123 123",
+            "12 a b",
+        ];
+
+        let expected = [
+            Ok(Node::directory("qwe")),
+            Ok(Node::directory("asdASD")),
+            Err("Invalid input - unknown node type"),
+            Err("Invalid input - no whitespace detected"),
+            Ok(Node::file("test", 1234)),
+            Ok(Node::file("example", 2356)),
+            Ok(Node::file("123", 123)),
+            Err("Invalid input - unexpected text after the name"),
+        ];
+
+        inputs
+            .iter()
+            .map(|&input| Node::try_from(input).map_err(|error| error.message))
+            .zip(expected.iter())
+            .for_each(|(result, expected)| {
+                assert_eq!(result, expected.clone().map_err(String::from));
+            });
+    }
+
+    #[test]
+    fn test_command_parsing() {
+        let inputs = [
+            "not a command",
+            "$ also not a command",
+            "$ cd test_dir",
+            "$ cd anotherTestDir",
+            "$ ls",
+            "$ le",
+            "$ lsx",
+            "$ ls -la",
+        ];
+
+        let expected = [
+            Err("Unknown command"),
+            Err("Unknown command"),
+            Ok(Command::ChangeDirectory("test_dir".to_string())),
+            Ok(Command::ChangeDirectory("anotherTestDir".to_string())),
+            Ok(Command::ListDirectory),
+            Err("Unknown command"),
+            Err("Unknown command"),
+            Err("Unknown command"),
+        ];
+
+        inputs
+            .iter()
+            .map(|&input| parse_command(input).map_err(|error| error.message))
+            .zip(expected.iter())
+            .for_each(|(result, expected)| {
+                assert_eq!(result, expected.clone().map_err(String::from));
+            });
+    }
+
+    #[test]
+    fn too_large_total_size_is_reported() {
+        let input = format!("$ cd /\n$ ls\n{} a\n1 b\n", usize::MAX);
+        match parse_input(&input) {
+            Err(Error::Parse(error)) => {
+                assert_eq!(error.message, "total size of all files is too large")
+            }
+            other => panic!("expected parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn entering_unknown_directory_is_reported() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n$ cd b\n";
+        match parse_input(input) {
+            Err(Error::Parse(error)) => {
+                assert_eq!(error.line, Some(6));
+                assert_eq!(error.message, "no directory named 'b' in /a");
+            }
+            other => panic!("expected parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn listing_a_directory_again_counts_its_files_once() {
+        let input = "$ cd /\n$ ls\ndir a\n10 b\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c\n";
+        let root = parse_input(input).unwrap();
+        assert_eq!(root.total_size(), 15);
+        assert_eq!(root.children.len(), 2);
+
+        let input = "$ cd /\n$ ls\n10 b\n$ ls\n20 b\n";
+        match parse_input(input) {
+            Err(Error::Parse(error)) => {
+                assert_eq!(error.line, Some(5));
+                assert_eq!(
+                    error.message,
+                    "'b' in / was listed before as something else"
+                );
+            }
+            other => panic!("expected parse error, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use aoc_common::{input::workspace_dir, Answer, Error, Part, Result};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+pub fn default_answers_path() -> std::path::PathBuf {
+    workspace_dir().join(DEFAULT_ANSWERS_FILE)
+}
+
+/// Layout of a single day in the answers file:
+///
+/// ```toml
+/// [day05]
+/// part1 = "CMZ"
+/// part2 = "MCD"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+/// Accepted answers to the real puzzle inputs.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+fn parse_day_key(key: &str) -> Option<u8> {
+    key.strip_prefix("day")
+        .filter(|number| number.len() == 2)
+        .and_then(|number| number.parse().ok())
+}
+
+impl KnownAnswers {
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let days: BTreeMap<String, DayAnswers> =
+            toml::from_str(text).map_err(|error| Error::parse(error.to_string()))?;
+
+        let mut answers = BTreeMap::new();
+        for (key, day_answers) in days {
+            let day = parse_day_key(&key).ok_or_else(|| {
+                Error::parse(format!("invalid day '{key}', expected name like 'day05'"))
+            })?;
+            let parts = [
+                (Part::One, day_answers.part1),
+                (Part::Two, day_answers.part2),
+            ];
+            for (part, answer) in parts {
+                if let Some(answer) = answer {
+                    answers.insert((day, part), answer);
+                }
+            }
+        }
+
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut days: BTreeMap<String, DayAnswers> = BTreeMap::new();
+        for (&(day, part), answer) in &self.answers {
+            let day_answers = days.entry(day_key(day)).or_default();
+            match part {
+                Part::One => day_answers.part1 = Some(answer.clone()),
+                Part::Two => day_answers.part2 = Some(answer.clone()),
+            }
+        }
+
+        // map of structs with string fields can always be serialized
+        toml::to_string(&days).unwrap()
+    }
+
+    /// Loads answers from `path`, missing file means that nothing is recorded yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text).map_err(|error| match error {
+                Error::Parse(error) => {
+                    Error::parse(format!("{}: {}", path.display(), error.message))
+                }
+                other => other,
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(fs::write(path, self.to_toml())?)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Records the answer as accepted, returning the previously recorded one.
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        self.answers.insert((day, part), answer.to_string())
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Answer, Part};
+
+    use crate::answers::{KnownAnswers, Verdict};
+
+    #[test]
+    fn answers_survive_round_trip() {
+        let mut answers = KnownAnswers::default();
+        answers.record(5, Part::One, &Answer::from("CMZ"));
+        answers.record(1, Part::Two, &Answer::from(45000i64));
+        answers.record(1, Part::One, &Answer::from(24000i64));
+
+        let text = answers.to_toml();
+        assert_eq!(
+            text,
+            "[day01]\npart1 = \"24000\"\npart2 = \"45000\"\n\n[day05]\npart1 = \"CMZ\"\n"
+        );
+        assert_eq!(KnownAnswers::from_toml(&text).unwrap(), answers);
+    }
+
+    #[test]
+    fn answers_are_checked() {
+        let answers = KnownAnswers::from_toml("[day02]\npart1 = \"15\"\n").unwrap();
+
+        assert_eq!(answers.check(2, Part::One, &15u32.into()), Verdict::Correct);
+        assert_eq!(
+            answers.check(2, Part::One, &16u32.into()),
+            Verdict::Wrong {
+                expected: String::from("15")
+            }
+        );
+        assert_eq!(answers.check(2, Part::Two, &12u32.into()), Verdict::Unknown);
+    }
+
+    #[test]
+    fn recording_returns_previous_answer() {
+        let mut answers = KnownAnswers::default();
+        assert_eq!(answers.record(3, Part::One, &157u32.into()), None);
+        assert_eq!(
+            answers.record(3, Part::One, &158u32.into()),
+            Some(String::from("157"))
+        );
+        assert_eq!(answers.get(3, Part::One), Some("158"));
+    }
+
+    #[test]
+    fn invalid_answers_files_are_rejected() {
+        assert!(KnownAnswers::from_toml("[day1]\npart1 = \"1\"\n").is_err());
+        assert!(KnownAnswers::from_toml("[day01]\npart3 = \"1\"\n").is_err());
+        assert!(KnownAnswers::from_toml("[day01]\npart1 = 1\n").is_err());
+        assert!(KnownAnswers::from_toml("day01 = ").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Part, Result, Solution};
+use serde::Serialize;
+
+pub const DEFAULT_ITERATIONS: u32 = 100;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Timing statistics of a single stage, all times are in nanoseconds.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Stats {
+    pub stage: Stage,
+    pub iterations: u32,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+    /// Input bytes processed per second, based on the mean time
+    pub throughput_bps: f64,
+}
+
+impl Stats {
+    pub fn from_samples(stage: Stage, samples: &mut [Duration], input_bytes: usize) -> Self {
+        samples.sort_unstable();
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        let count = samples.len().max(1);
+
+        let total: Duration = samples.iter().sum();
+        let mean = total / count as u32;
+        let median = match samples.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 1 => samples[len / 2],
+            len => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+        };
+        let throughput_bps = if mean.is_zero() {
+            0.0
+        } else {
+            input_bytes as f64 / mean.as_secs_f64()
+        };
+
+        Stats {
+            stage,
+            iterations: samples.len() as u32,
+            mean_ns: nanos(mean),
+            median_ns: nanos(median),
+            min_ns: samples.first().copied().map_or(0, nanos),
+            max_ns: samples.last().copied().map_or(0, nanos),
+            throughput_bps,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct DayBenchmark {
+    pub day: u8,
+    pub input_bytes: usize,
+    pub stages: Vec<Stats>,
+}
+
+/// Runs `f` for `iterations` times after a short warm-up, returning the time of every run.
+fn sample<T>(iterations: u32, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    for _ in 0..(iterations / 10).max(1) {
+        black_box(f());
+    }
+
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Measures parsing and each of the `parts` separately.
+pub fn benchmark<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    iterations: u32,
+) -> Result<DayBenchmark> {
+    let parsed = S::parse(input).map_err(|error| error.for_day(S::DAY))?;
+    let input_bytes = input.len();
+
+    let mut stages = vec![Stats::from_samples(
+        Stage::Parse,
+        &mut sample(iterations, || S::parse(black_box(input))),
+        input_bytes,
+    )];
+    for &part in parts {
+        stages.push(Stats::from_samples(
+            part.into(),
+            &mut sample(iterations, || S::answer(black_box(&parsed), part)),
+            input_bytes,
+        ));
+    }
+
+    Ok(DayBenchmark {
+        day: S::DAY,
+        input_bytes,
+        stages,
+    })
+}
+
+pub fn format_duration(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{nanos} ns"),
+        1_000..=999_999 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+pub fn format_throughput(bytes_per_second: f64) -> String {
+    format!("{:.1} MB/s", bytes_per_second / 1e6)
+}
+
+/// Renders the results as a table, one row per stage.
+pub fn to_table(benchmarks: &[DayBenchmark]) -> String {
+    let mut table = format!(
+        "{:<4} {:<7} {:>10} {:>10} {:>10} {:>14}\n",
+        "day", "stage", "mean", "median", "min", "throughput"
+    );
+    for benchmark in benchmarks {
+        for stats in &benchmark.stages {
+            table += &format!(
+                "{:<4} {:<7} {:>10} {:>10} {:>10} {:>14}\n",
+                format!("{:02}", benchmark.day),
+                stats.stage.to_string(),
+                format_duration(stats.mean_ns),
+                format_duration(stats.median_ns),
+                format_duration(stats.min_ns),
+                format_throughput(stats.throughput_bps)
+            );
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::{Answer, Part, Result, Solution};
+
+    use crate::bench::{benchmark, format_duration, Stage, Stats};
+
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u8 = 42;
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Ok(input.split(',').map(|n| n.parse().unwrap()).collect())
+        }
+
+        fn part1(input: &Vec<u32>) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(input: &Vec<u32>) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn stats_are_calculated_from_samples() {
+        let mut samples = [5, 1, 3, 9].map(Duration::from_micros);
+        let stats = Stats::from_samples(Stage::Parse, &mut samples, 1000);
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.mean_ns, 4500);
+        assert_eq!(stats.median_ns, 4000);
+        assert_eq!(stats.min_ns, 1000);
+        assert_eq!(stats.max_ns, 9000);
+        assert!((stats.throughput_bps - 1000.0 / 4.5e-6).abs() < 1.0);
+    }
+
+    #[test]
+    fn every_stage_is_measured() {
+        let result = benchmark::<Count>("1,2,3", &[Part::Two], 5).unwrap();
+
+        assert_eq!(result.day, 42);
+        assert_eq!(result.input_bytes, 5);
+        assert_eq!(
+            result
+                .stages
+                .iter()
+                .map(|stats| (stats.stage, stats.iterations))
+                .collect::<Vec<_>>(),
+            vec![(Stage::Parse, 5), (Stage::Part2, 5)]
+        );
+    }
+
+    #[test]
+    fn durations_are_formatted_with_units() {
+        assert_eq!(format_duration(999), "999 ns");
+        assert_eq!(format_duration(1_500), "1.50 µs");
+        assert_eq!(format_duration(2_250_000), "2.25 ms");
+        assert_eq!(format_duration(3_000_000_000), "3.00 s");
+    }
+}
@@ -0,0 +1,867 @@
+use std::{path::PathBuf, str::FromStr, time::Duration};
+
+use aoc_2022_01::formats::InputFormat;
+use aoc_common::Part;
+
+use crate::{bench::DEFAULT_ITERATIONS, days::DaySelection, player::DEFAULT_DELAY};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run (--day <DAY> | --all) [--part <PART>] [--input <PATH>] [--jobs <N>]
+            [--format <FORMAT>] [--details] [--stream]
+    aoc check (--day <DAY> | --all) [--input <PATH>] [--format <FORMAT>]
+    aoc calories [--input <PATH>] [--input-format <FORMAT>] [--lenient] [--top <N> | --stats]
+                 [--format <FORMAT>]
+    aoc verify [--day <DAY> | --all] [--part <PART>] [--answers <PATH>]
+    aoc record (--day <DAY> | --all) [--part <PART>] [--answers <PATH>]
+    aoc bench (--day <DAY> | --all) [--part <PART>] [--input <PATH>] [--iterations <N>]
+              [--format <FORMAT>]
+    aoc fetch (--day <DAY> | --all) [--input <PATH>] [--config <PATH>]
+    aoc new --day <DAY>
+    aoc gen --day <DAY> [--size <N>] [--seed <SEED>] [--output <PATH>]
+    aoc animate --day <DAY> [--input <PATH>] [--delay <MS>] [--output <PATH>]
+    aoc help
+
+Every command also accepts -v, -vv or -vvv.
+
+Commands:
+    run       solve puzzles and print the answers
+    check     validate inputs without solving them, reporting every problem found
+    calories  list elves of day 01 with calories of their food
+    verify    solve puzzles and compare the answers with the recorded ones
+    record    solve puzzles and record the answers as accepted
+    bench     measure the time of parsing and solving each part
+    fetch     download puzzle inputs, skipping the ones that are already there
+    new       create a crate for a new day from `template/` and register it
+    gen       generate a random, valid input of any size
+    animate   show the solution step by step (days 05 and 06), space pauses, n or → steps,
+              + and - change the speed, q quits
+
+Options:
+    -d, --day <DAY>      select a single day (1-25)
+    -a, --all            select every registered day, default for `verify`
+    -p, --part <PART>    select only one part (1 or 2), both are selected by default
+    -i, --input <PATH>   read the input from PATH, or from standard input if PATH is `-`
+    --input-format <FORMAT>
+                         layout of day 01 inventory, `lines`, `csv` or `json`, detected from
+                         the input by default (`auto`). Only for `calories`, `run` and `check`
+                         always detect it
+    --answers <PATH>     file with recorded answers, `answers.toml` in the workspace by default
+    -n, --iterations <N> number of measured runs of every stage, 100 by default
+    -j, --jobs <N>       number of days solved at the same time, 1 by default
+    -f, --format <FORMAT>
+                         output format, `text` (default) or `json`
+    --details            show details of solved days, like day 05's crate arrangements
+    --lenient            treat every line of day 01 that's not a number as a separator of elves,
+                         instead of rejecting it. Only `calories` reads inputs this way, `run`
+                         and `check` always take the puzzle's strict format
+    --top <N>            list only N elves of day 01 with the most calories, and the ones tied
+                         with the last of them
+    --stats              show statistics of all elves of day 01 instead of listing them
+    --stream             read the input bit by bit instead of loading all of it, for days that
+                         support it (01-04, 06), so inputs larger than memory can be solved.
+                         JSON inventories of day 01 are still read whole
+    -v, --verbose        log more, once for timings of every stage, twice for steps of solutions,
+                         three times for everything. Logs go to stderr. AOC_LOG environment
+                         variable can be used instead, with filters like `aoc_2022_05=debug`
+    --config <PATH>      configuration file, `aoc.toml` in the workspace by default
+    --size <N>           size of the generated input, in elves, rounds, groups, pairs, moves,
+                         marker offset or directories (depending on the day), similar to
+                         real inputs by default
+    --seed <SEED>        seed of the generated input, 0 by default
+    --delay <MS>         time every frame of the animation is shown for, 100 ms by default
+    -o, --output <PATH>  write the generated input, or all frames of the animation as text,
+                         to PATH instead of standard output or the terminal
+
+Unless --input is given, the input of day NN is read from $AOC_INPUT_DIR/NN/input,
+or NN/input in the workspace if AOC_INPUT_DIR is not set.
+The workspace is the closest directory with a `[workspace]` Cargo.toml at or above the current
+one, or the one `aoc` was built in when run from outside of any workspace.";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Selection {
+    pub days: DaySelection,
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("unknown format '{s}', expected 'text' or 'json'")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RunOptions {
+    pub selection: Selection,
+    pub input: Option<String>,
+    pub jobs: usize,
+    pub format: OutputFormat,
+    pub details: bool,
+    pub stream: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CheckOptions {
+    pub days: DaySelection,
+    pub input: Option<String>,
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CaloriesOptions {
+    pub input: Option<String>,
+    pub input_format: InputFormat,
+    pub lenient: bool,
+    pub top: Option<usize>,
+    pub stats: bool,
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AnswersOptions {
+    pub selection: Selection,
+    pub answers: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BenchOptions {
+    pub selection: Selection,
+    pub input: Option<String>,
+    pub iterations: u32,
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FetchOptions {
+    pub days: DaySelection,
+    /// where to save the input, only for a single day
+    pub input: Option<String>,
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GenOptions {
+    pub day: u8,
+    pub size: Option<usize>,
+    pub seed: u64,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AnimateOptions {
+    pub day: u8,
+    pub input: Option<String>,
+    pub delay: Duration,
+    /// Frames are written there instead of being played in the terminal
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    Run(RunOptions),
+    Check(CheckOptions),
+    Calories(CaloriesOptions),
+    Verify(AnswersOptions),
+    Record(AnswersOptions),
+    Bench(BenchOptions),
+    Fetch(FetchOptions),
+    New(u8),
+    Gen(GenOptions),
+    Animate(AnimateOptions),
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Flag {
+    Day,
+    All,
+    Part,
+    Input,
+    InputFormat,
+    Answers,
+    Iterations,
+    Jobs,
+    Format,
+    Config,
+    Details,
+    Lenient,
+    Top,
+    Stats,
+    Stream,
+    Size,
+    Seed,
+    Output,
+    Delay,
+}
+
+impl Flag {
+    fn from_arg(arg: &str) -> Option<Flag> {
+        match arg {
+            "-d" | "--day" => Some(Flag::Day),
+            "-a" | "--all" => Some(Flag::All),
+            "-p" | "--part" => Some(Flag::Part),
+            "-i" | "--input" => Some(Flag::Input),
+            "--input-format" => Some(Flag::InputFormat),
+            "--answers" => Some(Flag::Answers),
+            "-n" | "--iterations" => Some(Flag::Iterations),
+            "-j" | "--jobs" => Some(Flag::Jobs),
+            "-f" | "--format" => Some(Flag::Format),
+            "--config" => Some(Flag::Config),
+            "--details" => Some(Flag::Details),
+            "--lenient" => Some(Flag::Lenient),
+            "--top" => Some(Flag::Top),
+            "--stats" => Some(Flag::Stats),
+            "--stream" => Some(Flag::Stream),
+            "--size" => Some(Flag::Size),
+            "--seed" => Some(Flag::Seed),
+            "-o" | "--output" => Some(Flag::Output),
+            "--delay" => Some(Flag::Delay),
+            _ => None,
+        }
+    }
+
+    /// Whether the next argument is the flag's value.
+    fn takes_value(self) -> bool {
+        !matches!(
+            self,
+            Flag::All | Flag::Details | Flag::Lenient | Flag::Stats | Flag::Stream
+        )
+    }
+}
+
+/// Values of all flags given on the command line, before checking whether they make sense
+/// for the command.
+#[derive(Debug, Default)]
+struct Flags {
+    days: Option<DaySelection>,
+    part: Option<Part>,
+    input: Option<String>,
+    input_format: Option<InputFormat>,
+    answers: Option<PathBuf>,
+    iterations: Option<u32>,
+    jobs: Option<usize>,
+    format: Option<OutputFormat>,
+    config: Option<PathBuf>,
+    details: bool,
+    lenient: bool,
+    top: Option<usize>,
+    stats: bool,
+    stream: bool,
+    size: Option<usize>,
+    seed: Option<u64>,
+    output: Option<PathBuf>,
+    delay: Option<Duration>,
+}
+
+impl Flags {
+    /// --input makes sense only when a single day is selected.
+    fn single_day_input(&self, selection: &Selection) -> Result<Option<String>, String> {
+        if selection.days == DaySelection::All && self.input.is_some() {
+            return Err(String::from("--input can be used only with --day"));
+        }
+        Ok(self.input.clone())
+    }
+
+    fn selection(&self, default: Option<DaySelection>) -> Result<Selection, String> {
+        let days = self
+            .days
+            .or(default)
+            .ok_or_else(|| String::from("either --day or --all is required"))?;
+        Ok(Selection {
+            days,
+            part: self.part,
+        })
+    }
+}
+
+fn parse_number(value: Option<String>, flag: &str, range: (u8, u8)) -> Result<u8, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    match value.parse::<u8>() {
+        Ok(number) if (range.0..=range.1).contains(&number) => Ok(number),
+        _ => Err(format!(
+            "invalid value '{value}' for {flag}, expected a number between {} and {}",
+            range.0, range.1
+        )),
+    }
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("missing value for {flag}"))
+}
+
+fn parse_positive<T: FromStr + Default + PartialOrd>(
+    args: &mut impl Iterator<Item = String>,
+    flag: &str,
+) -> Result<T, String> {
+    let value = next_value(args, flag)?;
+    match value.parse::<T>() {
+        Ok(number) if number > T::default() => Ok(number),
+        _ => Err(format!(
+            "invalid value '{value}' for {flag}, expected a positive number"
+        )),
+    }
+}
+
+fn parse_flags(mut args: impl Iterator<Item = String>, allowed: &[Flag]) -> Result<Flags, String> {
+    let mut flags = Flags::default();
+
+    while let Some(arg) = args.next() {
+        let flag = Flag::from_arg(&arg)
+            .filter(|flag| allowed.contains(flag))
+            .ok_or_else(|| format!("unexpected argument '{arg}'"))?;
+        match flag {
+            Flag::Day | Flag::All => {
+                let days = match flag {
+                    Flag::Day => DaySelection::Single(parse_number(args.next(), &arg, (1, 25))?),
+                    _ => DaySelection::All,
+                };
+                if flags.days.replace(days).is_some() {
+                    return Err(String::from("--day and --all can be given only once"));
+                }
+            }
+            Flag::Part => {
+                flags.part = Part::try_from(parse_number(args.next(), &arg, (1, 2))?).ok();
+            }
+            Flag::Input => flags.input = Some(next_value(&mut args, &arg)?),
+            Flag::InputFormat => flags.input_format = Some(next_value(&mut args, &arg)?.parse()?),
+            Flag::Answers => flags.answers = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            Flag::Iterations => flags.iterations = Some(parse_positive(&mut args, &arg)?),
+            Flag::Jobs => flags.jobs = Some(parse_positive(&mut args, &arg)?),
+            Flag::Format => flags.format = Some(next_value(&mut args, &arg)?.parse()?),
+            Flag::Config => flags.config = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            Flag::Details => flags.details = true,
+            Flag::Lenient => flags.lenient = true,
+            Flag::Top => flags.top = Some(parse_positive(&mut args, &arg)?),
+            Flag::Stats => flags.stats = true,
+            Flag::Stream => flags.stream = true,
+            Flag::Size => flags.size = Some(parse_positive(&mut args, &arg)?),
+            Flag::Seed => {
+                let value = next_value(&mut args, &arg)?;
+                let seed = value.parse().map_err(|_| {
+                    format!("invalid value '{value}' for {arg}, expected a non-negative number")
+                })?;
+                flags.seed = Some(seed);
+            }
+            Flag::Output => flags.output = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            Flag::Delay => {
+                flags.delay = Some(Duration::from_millis(parse_positive(&mut args, &arg)?))
+            }
+        }
+    }
+
+    Ok(flags)
+}
+
+fn parse_run_args(args: impl Iterator<Item = String>) -> Result<RunOptions, String> {
+    let flags = parse_flags(
+        args,
+        &[
+            Flag::Day,
+            Flag::All,
+            Flag::Part,
+            Flag::Input,
+            Flag::Jobs,
+            Flag::Format,
+            Flag::Details,
+            Flag::Stream,
+        ],
+    )?;
+    let selection = flags.selection(None)?;
+    Ok(RunOptions {
+        selection,
+        input: flags.single_day_input(&selection)?,
+        jobs: flags.jobs.unwrap_or(1),
+        format: flags.format.unwrap_or_default(),
+        details: flags.details,
+        stream: flags.stream,
+    })
+}
+
+fn parse_check_args(args: impl Iterator<Item = String>) -> Result<CheckOptions, String> {
+    let flags = parse_flags(args, &[Flag::Day, Flag::All, Flag::Input, Flag::Format])?;
+    let selection = flags.selection(None)?;
+    Ok(CheckOptions {
+        days: selection.days,
+        input: flags.single_day_input(&selection)?,
+        format: flags.format.unwrap_or_default(),
+    })
+}
+
+fn parse_calories_args(args: impl Iterator<Item = String>) -> Result<CaloriesOptions, String> {
+    let flags = parse_flags(
+        args,
+        &[
+            Flag::Input,
+            Flag::InputFormat,
+            Flag::Lenient,
+            Flag::Top,
+            Flag::Stats,
+            Flag::Format,
+        ],
+    )?;
+    if flags.top.is_some() && flags.stats {
+        return Err(String::from("--top and --stats can't be used together"));
+    }
+    Ok(CaloriesOptions {
+        input: flags.input,
+        input_format: flags.input_format.unwrap_or_default(),
+        lenient: flags.lenient,
+        top: flags.top,
+        stats: flags.stats,
+        format: flags.format.unwrap_or_default(),
+    })
+}
+
+fn parse_bench_args(args: impl Iterator<Item = String>) -> Result<BenchOptions, String> {
+    let flags = parse_flags(
+        args,
+        &[
+            Flag::Day,
+            Flag::All,
+            Flag::Part,
+            Flag::Input,
+            Flag::Iterations,
+            Flag::Format,
+        ],
+    )?;
+    let selection = flags.selection(None)?;
+    Ok(BenchOptions {
+        selection,
+        input: flags.single_day_input(&selection)?,
+        iterations: flags.iterations.unwrap_or(DEFAULT_ITERATIONS),
+        format: flags.format.unwrap_or_default(),
+    })
+}
+
+fn parse_answers_args(
+    args: impl Iterator<Item = String>,
+    default_days: Option<DaySelection>,
+) -> Result<AnswersOptions, String> {
+    let flags = parse_flags(args, &[Flag::Day, Flag::All, Flag::Part, Flag::Answers])?;
+    Ok(AnswersOptions {
+        selection: flags.selection(default_days)?,
+        answers: flags.answers,
+    })
+}
+
+fn parse_fetch_args(args: impl Iterator<Item = String>) -> Result<FetchOptions, String> {
+    let flags = parse_flags(args, &[Flag::Day, Flag::All, Flag::Input, Flag::Config])?;
+    let selection = flags.selection(None)?;
+    Ok(FetchOptions {
+        days: selection.days,
+        input: flags.single_day_input(&selection)?,
+        config: flags.config,
+    })
+}
+
+fn parse_new_args(args: impl Iterator<Item = String>) -> Result<u8, String> {
+    let flags = parse_flags(args, &[Flag::Day])?;
+    match flags.days {
+        Some(DaySelection::Single(day)) => Ok(day),
+        _ => Err(String::from("--day is required")),
+    }
+}
+
+fn parse_gen_args(args: impl Iterator<Item = String>) -> Result<GenOptions, String> {
+    let flags = parse_flags(args, &[Flag::Day, Flag::Size, Flag::Seed, Flag::Output])?;
+    let Some(DaySelection::Single(day)) = flags.days else {
+        return Err(String::from("--day is required"));
+    };
+    Ok(GenOptions {
+        day,
+        size: flags.size,
+        seed: flags.seed.unwrap_or(0),
+        output: flags.output,
+    })
+}
+
+fn parse_animate_args(args: impl Iterator<Item = String>) -> Result<AnimateOptions, String> {
+    let flags = parse_flags(args, &[Flag::Day, Flag::Input, Flag::Delay, Flag::Output])?;
+    let Some(DaySelection::Single(day)) = flags.days else {
+        return Err(String::from("--day is required"));
+    };
+    Ok(AnimateOptions {
+        day,
+        input: flags.input,
+        delay: flags.delay.unwrap_or(DEFAULT_DELAY),
+        output: flags.output,
+    })
+}
+
+/// Removes verbosity flags (`-v`, `-vv`, `--verbose`...) from the arguments, wherever they are
+/// except as the value of another flag, and counts them.
+pub fn take_verbosity(args: impl IntoIterator<Item = String>) -> (u8, Vec<String>) {
+    let mut verbosity = 0u8;
+    let mut is_value = false;
+    let rest = args
+        .into_iter()
+        .filter(|arg| {
+            if std::mem::take(&mut is_value) {
+                return true;
+            }
+            let count = match arg.as_str() {
+                "--verbose" => 1,
+                flag if flag.len() > 1
+                    && flag.starts_with('-')
+                    && flag[1..].bytes().all(|c| c == b'v') =>
+                {
+                    flag.len() - 1
+                }
+                other => {
+                    is_value = Flag::from_arg(other).is_some_and(Flag::takes_value);
+                    return true;
+                }
+            };
+            verbosity = verbosity.saturating_add(u8::try_from(count).unwrap_or(u8::MAX));
+            false
+        })
+        .collect();
+    (verbosity, rest)
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run_args(args)?)),
+        Some("check") => Ok(Command::Check(parse_check_args(args)?)),
+        Some("calories") => Ok(Command::Calories(parse_calories_args(args)?)),
+        Some("verify") => Ok(Command::Verify(parse_answers_args(
+            args,
+            Some(DaySelection::All),
+        )?)),
+        Some("record") => Ok(Command::Record(parse_answers_args(args, None)?)),
+        Some("bench") => Ok(Command::Bench(parse_bench_args(args)?)),
+        Some("fetch") => Ok(Command::Fetch(parse_fetch_args(args)?)),
+        Some("new") => Ok(Command::New(parse_new_args(args)?)),
+        Some("gen") => Ok(Command::Gen(parse_gen_args(args)?)),
+        Some("animate") => Ok(Command::Animate(parse_animate_args(args)?)),
+        Some("help" | "-h" | "--help") | None => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command '{other}'")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use aoc_2022_01::formats::InputFormat;
+    use aoc_common::Part;
+
+    use crate::{
+        cli::{
+            parse_args, take_verbosity, AnimateOptions, AnswersOptions, BenchOptions,
+            CaloriesOptions, CheckOptions, Command, FetchOptions, GenOptions, OutputFormat,
+            RunOptions, Selection,
+        },
+        days::DaySelection,
+    };
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn run_arguments_are_parsed() {
+        assert_eq!(
+            parse("run --day 5 --part 2"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection {
+                    days: DaySelection::Single(5),
+                    part: Some(Part::Two)
+                },
+                input: None,
+                jobs: 1,
+                format: OutputFormat::Text,
+                details: false,
+                stream: false
+            }))
+        );
+        assert_eq!(
+            parse("run -a -j 4 --format json --details"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection {
+                    days: DaySelection::All,
+                    part: None
+                },
+                input: None,
+                jobs: 4,
+                format: OutputFormat::Json,
+                details: true,
+                stream: false
+            }))
+        );
+        assert_eq!(
+            parse("run -d 1 --input - --stream"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection {
+                    days: DaySelection::Single(1),
+                    part: None
+                },
+                input: Some(String::from("-")),
+                jobs: 1,
+                format: OutputFormat::Text,
+                details: false,
+                stream: true
+            }))
+        );
+        assert_eq!(parse(""), Ok(Command::Help));
+        assert_eq!(parse("--help"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn check_arguments_are_parsed() {
+        assert_eq!(
+            parse("check --all --format json"),
+            Ok(Command::Check(CheckOptions {
+                days: DaySelection::All,
+                input: None,
+                format: OutputFormat::Json
+            }))
+        );
+        assert_eq!(
+            parse("check -d 4 -i odd.txt"),
+            Ok(Command::Check(CheckOptions {
+                days: DaySelection::Single(4),
+                input: Some(String::from("odd.txt")),
+                format: OutputFormat::Text
+            }))
+        );
+    }
+
+    #[test]
+    fn calories_arguments_are_parsed() {
+        assert_eq!(
+            parse("calories"),
+            Ok(Command::Calories(CaloriesOptions {
+                input: None,
+                input_format: InputFormat::Auto,
+                lenient: false,
+                top: None,
+                stats: false,
+                format: OutputFormat::Text
+            }))
+        );
+        assert_eq!(
+            parse("calories -i - --input-format csv --lenient --top 5 -f json"),
+            Ok(Command::Calories(CaloriesOptions {
+                input: Some(String::from("-")),
+                input_format: InputFormat::Csv,
+                lenient: true,
+                top: Some(5),
+                stats: false,
+                format: OutputFormat::Json
+            }))
+        );
+        assert_eq!(
+            parse("calories --stats"),
+            Ok(Command::Calories(CaloriesOptions {
+                input: None,
+                input_format: InputFormat::Auto,
+                lenient: false,
+                top: None,
+                stats: true,
+                format: OutputFormat::Text
+            }))
+        );
+    }
+
+    #[test]
+    fn answers_arguments_are_parsed() {
+        assert_eq!(
+            parse("verify"),
+            Ok(Command::Verify(AnswersOptions {
+                selection: Selection {
+                    days: DaySelection::All,
+                    part: None
+                },
+                answers: None
+            }))
+        );
+        assert_eq!(
+            parse("record --day 3 --part 1 --answers my.toml"),
+            Ok(Command::Record(AnswersOptions {
+                selection: Selection {
+                    days: DaySelection::Single(3),
+                    part: Some(Part::One)
+                },
+                answers: Some(PathBuf::from("my.toml"))
+            }))
+        );
+    }
+
+    #[test]
+    fn bench_arguments_are_parsed() {
+        assert_eq!(
+            parse("bench --all -n 20 --format json"),
+            Ok(Command::Bench(BenchOptions {
+                selection: Selection {
+                    days: DaySelection::All,
+                    part: None
+                },
+                input: None,
+                iterations: 20,
+                format: OutputFormat::Json
+            }))
+        );
+        assert_eq!(
+            parse("bench --day 6 --part 1"),
+            Ok(Command::Bench(BenchOptions {
+                selection: Selection {
+                    days: DaySelection::Single(6),
+                    part: Some(Part::One)
+                },
+                input: None,
+                iterations: 100,
+                format: OutputFormat::Text
+            }))
+        );
+    }
+
+    #[test]
+    fn fetch_arguments_are_parsed() {
+        assert_eq!(
+            parse("fetch --day 8 --config ~/aoc.toml"),
+            Ok(Command::Fetch(FetchOptions {
+                days: DaySelection::Single(8),
+                input: None,
+                config: Some(PathBuf::from("~/aoc.toml"))
+            }))
+        );
+        assert_eq!(
+            parse("fetch --day 2 -i day2.txt"),
+            Ok(Command::Fetch(FetchOptions {
+                days: DaySelection::Single(2),
+                input: Some(String::from("day2.txt")),
+                config: None
+            }))
+        );
+        assert_eq!(parse("new -d 8"), Ok(Command::New(8)));
+        assert_eq!(
+            parse("gen -d 3 --size 5000 --seed 42 -o big.txt"),
+            Ok(Command::Gen(GenOptions {
+                day: 3,
+                size: Some(5000),
+                seed: 42,
+                output: Some(PathBuf::from("big.txt"))
+            }))
+        );
+        assert_eq!(
+            parse("animate -d 5 --delay 250 -o frames.txt"),
+            Ok(Command::Animate(AnimateOptions {
+                day: 5,
+                input: None,
+                delay: Duration::from_millis(250),
+                output: Some(PathBuf::from("frames.txt"))
+            }))
+        );
+    }
+
+    #[test]
+    fn verbosity_flags_are_counted_anywhere() {
+        let args = |args: &str| {
+            args.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(take_verbosity(args("run -d 5")), (0, args("run -d 5")));
+        assert_eq!(take_verbosity(args("-v run -d 5")), (1, args("run -d 5")));
+        assert_eq!(
+            take_verbosity(args("run -vv -d 5 --verbose")),
+            (3, args("run -d 5"))
+        );
+        assert_eq!(
+            take_verbosity(args("run -d 5 -x")),
+            (0, args("run -d 5 -x"))
+        );
+        assert_eq!(
+            take_verbosity(args("run -d 5 --input -v --details -v")),
+            (1, args("run -d 5 --input -v --details"))
+        );
+        assert_eq!(
+            take_verbosity(vec![format!("-{}", "v".repeat(300))]),
+            (u8::MAX, vec![])
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        let invalid = [
+            "run",
+            "run --day",
+            "run --day 0",
+            "run --day 26",
+            "run --day five",
+            "run --all --part 3",
+            "run --all --day 3",
+            "run --all --quiet",
+            "run --all --input input.txt",
+            "run --day 2 --input",
+            "run --day 2 --answers answers.toml",
+            "verify --input input.txt",
+            "record",
+            "record --all --answers",
+            "bench --all --iterations 0",
+            "run --all --jobs 0",
+            "run --all --jobs many",
+            "bench --all --jobs 2",
+            "bench --all --format yaml",
+            "bench --all --input input.txt",
+            "run --all --format yaml",
+            "verify --details",
+            "run --all --config aoc.toml",
+            "fetch",
+            "fetch --all --part 1",
+            "fetch --all --input input.txt",
+            "new",
+            "new --all",
+            "new --day 8 --part 1",
+            "gen --size 10",
+            "gen --all",
+            "gen --day 1 --size 0",
+            "gen --day 1 --seed -1",
+            "check",
+            "check --all --input input.txt",
+            "check --day 2 --part 1",
+            "calories --day 1",
+            "calories --top 0",
+            "calories --top",
+            "calories --top 3 --stats",
+            "calories --input-format xml",
+            "run --day 1 --input-format csv",
+            "run --day 1 --top 3",
+            "run --day 1 --lenient",
+            "animate --all",
+            "animate --day 5 --delay 0",
+            "animate --day 5 --part 1",
+            "walk --all",
+        ];
+
+        for args in invalid {
+            assert!(parse(args).is_err(), "'{args}' should be rejected");
+        }
+    }
+}
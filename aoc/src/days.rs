@@ -0,0 +1,209 @@
+use std::{
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    animation::Screen, check::Diagnostic, input::InputSource, Answer, Part, Result, Solution,
+    StreamingSolution,
+};
+use tracing::info_span;
+
+use crate::bench::{self, DayBenchmark};
+
+/// Parses the input once and answers all requested parts.
+pub type Solver = fn(&str, &[Part]) -> Result<Solved>;
+
+/// Answers all requested parts in a single pass over the input, without loading all of it.
+/// Both parts are answered while reading, so the whole time is counted as parsing.
+pub type StreamSolver = fn(&mut dyn BufRead, &[Part]) -> Result<Solved>;
+
+/// Parses the input and describes it, for days that have something to show.
+pub type Details = fn(&str) -> Result<Option<String>>;
+
+/// Finds every problem with the input, without solving anything.
+pub type Check = fn(&str) -> Vec<Diagnostic>;
+
+/// Parses the input and plays the day's animation on the screen.
+/// Returns `false` if the day has no animation.
+pub type Animate = fn(&str, &mut dyn Screen) -> Result<bool>;
+
+/// Measures parsing and requested parts over given number of iterations.
+pub type Benchmark = fn(&str, &[Part], u32) -> Result<DayBenchmark>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DaySelection {
+    All,
+    Single(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PartAnswer {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Answers of a single day, with time taken by each stage.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Solved {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub answers: Vec<PartAnswer>,
+}
+
+impl Solved {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers
+            .iter()
+            .find(|answer| answer.part == part)
+            .map(|answer| &answer.answer)
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    /// Only for days that implement `StreamingSolution`
+    pub stream: Option<StreamSolver>,
+    pub details: Details,
+    pub check: Check,
+    pub animate: Animate,
+    pub bench: Benchmark,
+}
+
+impl Day {
+    pub fn solve_from(&self, source: &InputSource, parts: &[Part]) -> Result<Solved> {
+        (self.solve)(&source.read()?, parts)
+    }
+
+    /// Like `solve_from`, but reads the input bit by bit if the day can be solved that way.
+    pub fn stream_from(&self, source: &InputSource, parts: &[Part]) -> Result<Solved> {
+        match self.stream {
+            Some(stream) => stream(&mut source.open()?, parts),
+            None => self.solve_from(source, parts),
+        }
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved> {
+    let start = Instant::now();
+    let input = info_span!("parse", day = S::DAY)
+        .in_scope(|| S::parse(input))
+        .map_err(|error| error.for_day(S::DAY))?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = info_span!("solve", day = S::DAY, part = part as u8)
+                .in_scope(|| S::answer(&input, part));
+            PartAnswer {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Solved {
+        day: S::DAY,
+        parse_time,
+        answers,
+    })
+}
+
+fn solve_stream<S: StreamingSolution>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Solved> {
+    let start = Instant::now();
+    let [part1, part2] = info_span!("stream", day = S::DAY)
+        .in_scope(|| S::solve_stream(reader))
+        .map_err(|error| error.for_day(S::DAY))?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| PartAnswer {
+            part,
+            answer: match part {
+                Part::One => part1.clone(),
+                Part::Two => part2.clone(),
+            },
+            elapsed: Duration::ZERO,
+        })
+        .collect();
+
+    Ok(Solved {
+        day: S::DAY,
+        parse_time,
+        answers,
+    })
+}
+
+fn details<S: Solution>(input: &str) -> Result<Option<String>> {
+    let input = S::parse(input).map_err(|error| error.for_day(S::DAY))?;
+    Ok(S::details(&input))
+}
+
+fn check<S: Solution>(input: &str) -> Vec<Diagnostic> {
+    let _span = info_span!("check", day = S::DAY).entered();
+    S::check(input)
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            problem: diagnostic.problem.for_day(S::DAY),
+            ..diagnostic
+        })
+        .collect()
+}
+
+fn animate<S: Solution>(input: &str, screen: &mut dyn Screen) -> Result<bool> {
+    let input = S::parse(input).map_err(|error| error.for_day(S::DAY))?;
+    let Some(frames) = S::animation(&input) else {
+        return Ok(false);
+    };
+    screen.play(frames)?;
+    Ok(true)
+}
+
+macro_rules! day {
+    ($solution:path) => {
+        day!($solution, None)
+    };
+    ($solution:path, streaming) => {
+        day!($solution, Some(solve_stream::<$solution>))
+    };
+    ($solution:path, $stream:expr) => {
+        Day {
+            number: <$solution as Solution>::DAY,
+            solve: solve::<$solution>,
+            stream: $stream,
+            details: details::<$solution>,
+            check: check::<$solution>,
+            animate: animate::<$solution>,
+            bench: bench::benchmark::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(aoc_2022_01::Day01, streaming),
+    day!(aoc_2022_02::Day02, streaming),
+    day!(aoc_2022_03::Day03, streaming),
+    day!(aoc_2022_04::Day04, streaming),
+    day!(aoc_2022_05::Day05),
+    day!(aoc_2022_06::Day06, streaming),
+    day!(aoc_2022_07::Day07),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub fn select(selection: DaySelection) -> std::result::Result<Vec<&'static Day>, String> {
+    match selection {
+        DaySelection::All => Ok(DAYS.iter().collect()),
+        DaySelection::Single(number) => find(number)
+            .map(|day| vec![day])
+            .ok_or_else(|| format!("Day {number:02} is not solved yet")),
+    }
+}
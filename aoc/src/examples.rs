@@ -0,0 +1,101 @@
+use std::{fs, path::Path};
+
+use aoc_common::{input::day_dir, Error, Part, Result};
+
+pub const INPUT_EXTENSION: &str = "input";
+pub const EXPECTED_EXTENSION: &str = "expected";
+
+/// Example from the puzzle description, stored in `NN/examples/<name>.input`,
+/// with expected answers in `NN/examples/<name>.expected`:
+///
+/// ```text
+/// part1 = 24000
+/// part2 = 45000
+/// ```
+///
+/// Parts without an expected answer are not checked.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+}
+
+fn parse_expected(text: &str) -> std::result::Result<Vec<(Part, String)>, String> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("expected 'partN = answer', found '{line}'"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                other => return Err(format!("unknown part '{other}'")),
+            };
+            Ok((part, value.trim().to_string()))
+        })
+        .collect()
+}
+
+fn load_example(input_path: &Path) -> Result<Example> {
+    let name = input_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let expected_path = input_path.with_extension(EXPECTED_EXTENSION);
+    let expected = parse_expected(&fs::read_to_string(&expected_path)?)
+        .map_err(|message| Error::parse(format!("{}: {message}", expected_path.display())))?;
+
+    Ok(Example {
+        name,
+        input: fs::read_to_string(input_path)?,
+        expected,
+    })
+}
+
+/// Loads all examples from `directory`, sorted by name.
+pub fn load_examples_from(directory: &Path) -> Result<Vec<Example>> {
+    if !directory.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut paths = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == INPUT_EXTENSION)
+    });
+    paths.sort();
+
+    paths.iter().map(|path| load_example(path)).collect()
+}
+
+pub fn load_examples(day: u8) -> Result<Vec<Example>> {
+    load_examples_from(&day_dir(day).join("examples"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Part;
+
+    use crate::examples::parse_expected;
+
+    #[test]
+    fn expected_answers_are_parsed() {
+        assert_eq!(
+            parse_expected("part1 = 24000\n\npart2 =  CMZ \n"),
+            Ok(vec![
+                (Part::One, String::from("24000")),
+                (Part::Two, String::from("CMZ"))
+            ])
+        );
+        assert_eq!(
+            parse_expected("part2 = 12\n"),
+            Ok(vec![(Part::Two, String::from("12"))])
+        );
+        assert!(parse_expected("part3 = 1").is_err());
+        assert!(parse_expected("part1: 1").is_err());
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::input::workspace_dir;
+use serde::Deserialize;
+
+pub const YEAR: u16 = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_MIN_INTERVAL_SECS: f64 = 5.0;
+pub const DEFAULT_CONFIG_FILE: &str = "aoc.toml";
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str =
+    "github.com/SteelPh0enix/AdventOfCode2022 by steelphoenix2137@gmail.com (aoc fetch)";
+
+pub fn default_config_path() -> PathBuf {
+    workspace_dir().join(DEFAULT_CONFIG_FILE)
+}
+
+/// File storing the time of the last request, shared by all fetches from this workspace.
+pub fn default_throttle_path() -> PathBuf {
+    workspace_dir().join(".aoc_last_request")
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Config(String),
+    Http { status: u16, message: String },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session token, set `session` in the [fetch] section of {DEFAULT_CONFIG_FILE} \
+                 or the {SESSION_VARIABLE} environment variable"
+            ),
+            FetchError::Config(message) => write!(f, "invalid configuration: {message}"),
+            FetchError::Http { status, message } => {
+                write!(f, "server responded with {status}: {message}")
+            }
+            FetchError::Transport(message) => write!(f, "request failed: {message}"),
+            FetchError::Io(error) => write!(f, "I/O error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(error: io::Error) -> Self {
+        FetchError::Io(error)
+    }
+}
+
+/// `[fetch]` section of `aoc.toml`:
+///
+/// ```toml
+/// [fetch]
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// min_interval_secs = 5
+/// ```
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FetchConfig {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub min_interval_secs: f64,
+}
+
+impl Default for FetchConfig {
+    fn default() -> Self {
+        FetchConfig {
+            session: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+            min_interval_secs: DEFAULT_MIN_INTERVAL_SECS,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    fetch: FetchConfig,
+}
+
+impl FetchConfig {
+    pub fn from_toml(text: &str) -> Result<Self, FetchError> {
+        let config: ConfigFile =
+            toml::from_str(text).map_err(|error| FetchError::Config(error.to_string()))?;
+        if !config.fetch.min_interval_secs.is_finite() || config.fetch.min_interval_secs < 0.0 {
+            return Err(FetchError::Config(String::from(
+                "min_interval_secs must be a non-negative number",
+            )));
+        }
+        Ok(config.fetch)
+    }
+
+    /// Loads the configuration from `path` (missing file means defaults),
+    /// then applies overrides from the environment.
+    pub fn load(path: &Path) -> Result<Self, FetchError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(error) => return Err(error.into()),
+        };
+
+        if let Ok(session) = env::var(SESSION_VARIABLE) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VARIABLE) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        Duration::from_secs_f64(self.min_interval_secs)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FetchOutcome {
+    Cached,
+    Downloaded,
+}
+
+pub struct Fetcher {
+    config: FetchConfig,
+    throttle_path: PathBuf,
+    agent: ureq::Agent,
+}
+
+fn unix_time() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+impl Fetcher {
+    pub fn new(config: FetchConfig, throttle_path: PathBuf) -> Self {
+        Fetcher {
+            config,
+            throttle_path,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    pub fn input_url(&self, day: u8) -> String {
+        format!(
+            "{}/{YEAR}/day/{day}/input",
+            self.config.base_url.trim_end_matches('/')
+        )
+    }
+
+    /// Waits until at least the minimum interval has passed since the last request.
+    fn throttle(&self) {
+        let last_request = fs::read_to_string(&self.throttle_path)
+            .ok()
+            .and_then(|text| text.trim().parse::<u128>().ok())
+            .map(|millis| Duration::from_millis(millis as u64));
+
+        if let Some(last_request) = last_request {
+            let elapsed = unix_time().saturating_sub(last_request);
+            if let Some(remaining) = self.config.min_interval().checked_sub(elapsed) {
+                thread::sleep(remaining);
+            }
+        }
+    }
+
+    fn mark_request(&self) -> io::Result<()> {
+        fs::write(&self.throttle_path, unix_time().as_millis().to_string())
+    }
+
+    fn download(&self, day: u8) -> Result<String, FetchError> {
+        let session = self
+            .config
+            .session
+            .as_deref()
+            .ok_or(FetchError::MissingSession)?;
+
+        self.throttle();
+        let response = self
+            .agent
+            .get(&self.input_url(day))
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        self.mark_request()?;
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => Err(FetchError::Http {
+                status,
+                message: response
+                    .into_string()
+                    .map(|body| body.trim().to_string())
+                    .unwrap_or_default(),
+            }),
+            Err(error) => Err(FetchError::Transport(error.to_string())),
+        }
+    }
+
+    /// Downloads the input of `day` into `destination`, unless it's already there.
+    pub fn fetch(&self, day: u8, destination: &Path) -> Result<FetchOutcome, FetchError> {
+        if fs::metadata(destination).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(FetchOutcome::Cached);
+        }
+
+        let input = self.download(day)?;
+        if let Some(directory) = destination.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        // write to a temporary file first, so an interrupted write doesn't look like a cached input
+        let partial = destination.with_extension("partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, destination)?;
+        Ok(FetchOutcome::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::{FetchConfig, Fetcher, DEFAULT_BASE_URL};
+
+    #[test]
+    fn config_is_parsed_with_defaults() {
+        assert_eq!(FetchConfig::from_toml("").unwrap(), FetchConfig::default());
+
+        let config = FetchConfig::from_toml(
+            "[fetch]\nsession = \"abc\"\nbase_url = \"http://localhost:8080/\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.min_interval_secs, 5.0);
+
+        let fetcher = Fetcher::new(config, "unused".into());
+        assert_eq!(
+            fetcher.input_url(5),
+            "http://localhost:8080/2022/day/5/input"
+        );
+        assert!(DEFAULT_BASE_URL.starts_with("https://"));
+    }
+
+    #[test]
+    fn invalid_config_is_rejected() {
+        assert!(FetchConfig::from_toml("[fetch]\nsessoin = \"abc\"\n").is_err());
+        assert!(FetchConfig::from_toml("[fetch]\nmin_interval_secs = -1\n").is_err());
+        assert!(FetchConfig::from_toml("[fetch]\nmin_interval_secs = \"1\"\n").is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::HashSet;
+
+/// Days that have an input generator.
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=7;
+
+/// Size close to the one of real inputs. What it means depends on the day:
+/// elves (01), rounds (02), groups of three rucksacks (03), pairs (04), crane moves (05),
+/// offset of the start-of-message marker (06) and directories (07).
+pub fn default_size(day: u8) -> usize {
+    match day {
+        1 => 250,
+        2 => 2500,
+        3 => 100,
+        4 => 1000,
+        5 => 500,
+        6 => 2800,
+        _ => 200,
+    }
+}
+
+/// Small deterministic generator (splitmix64), so the same seed gives the same input
+/// on every platform and with every version of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in the inclusive range, which must not be empty.
+    fn between(&mut self, low: usize, high: usize) -> usize {
+        low + (self.next() % (high - low + 1) as u64) as usize
+    }
+
+    fn choose<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.between(0, items.len() - 1)]
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.between(0, i));
+        }
+    }
+}
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+fn calories(rng: &mut Rng, elves: usize) -> String {
+    (0..elves)
+        .map(|_| {
+            (0..rng.between(1, 15))
+                .map(|_| format!("{}\n", rng.between(1000, 60000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn strategy_guide(rng: &mut Rng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            let opponent = rng.choose(&['A', 'B', 'C']);
+            let response = rng.choose(&['X', 'Y', 'Z']);
+            format!("{opponent} {response}\n")
+        })
+        .collect()
+}
+
+/// Compartment of given length, made of `required` items and random ones from `allowed`.
+fn compartment(rng: &mut Rng, length: usize, required: &[u8], allowed: &[u8]) -> Vec<u8> {
+    let mut items = required.to_vec();
+    while items.len() < length {
+        items.push(rng.choose(allowed));
+    }
+    rng.shuffle(&mut items);
+    items
+}
+
+/// Every rucksack of a group uses its own pool of items, so the badge is the only item
+/// all three have. Compartments of a rucksack share only a single item for the same reason.
+fn rucksacks(rng: &mut Rng, groups: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..groups {
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+        let badge = letters.pop().unwrap();
+
+        for pool in letters.chunks(letters.len() / 3).take(3) {
+            let mut pool = pool.to_vec();
+            pool.push(badge);
+            rng.shuffle(&mut pool);
+            let shared = pool.pop().unwrap();
+            let (first, second) = pool.split_at(pool.len() / 2);
+
+            // the badge has to be somewhere, unless it's the shared item already
+            let (mut first_required, mut second_required) = (vec![shared], vec![shared]);
+            if first.contains(&badge) {
+                first_required.push(badge);
+            } else if second.contains(&badge) {
+                second_required.push(badge);
+            }
+
+            let length = rng.between(2, 16);
+            let first = compartment(rng, length, &first_required, first);
+            let second = compartment(rng, length, &second_required, second);
+            output.extend(first.into_iter().chain(second).map(char::from));
+            output.push('\n');
+        }
+    }
+    output
+}
+
+fn section_pairs(rng: &mut Rng, pairs: usize) -> String {
+    let mut sections = || {
+        let start = rng.between(1, 99);
+        let end = rng.between(start, 99);
+        format!("{start}-{end}")
+    };
+    (0..pairs)
+        .map(|_| format!("{},{}\n", sections(), sections()))
+        .collect()
+}
+
+/// Nine stacks of crates and legal moves - the crane never takes more crates than the stack has.
+fn crane_procedure(rng: &mut Rng, moves: usize) -> String {
+    let mut stacks = (0..9)
+        .map(|_| {
+            (0..rng.between(1, 8))
+                .map(|_| rng.choose(&LETTERS[26..]))
+                .collect::<Vec<u8>>()
+        })
+        .collect::<Vec<Vec<u8>>>();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut output = String::new();
+    for row in (0..height).rev() {
+        let line = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(&name) => format!("[{}]", name as char),
+                None => String::from("   "),
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        output += line.trim_end();
+        output.push('\n');
+    }
+    let numbers = (1..=stacks.len())
+        .map(|number| format!(" {number} "))
+        .collect::<Vec<String>>();
+    output += &format!("{}\n\n", numbers.join(" "));
+
+    for _ in 0..moves {
+        let non_empty = (0..stacks.len())
+            .filter(|&stack| !stacks[stack].is_empty())
+            .collect::<Vec<usize>>();
+        let from = rng.choose(&non_empty);
+        let to = rng.between(0, stacks.len() - 1);
+        let amount = rng.between(1, stacks[from].len().min(5));
+
+        let remaining = stacks[from].len() - amount;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].extend(moved);
+        output += &format!("move {amount} from {} to {}\n", from + 1, to + 1);
+    }
+    output
+}
+
+/// Datastream with the first start-of-message marker (14 different characters) ending at `offset`.
+/// Everything before it uses only 13 letters, so no earlier window can be a marker.
+fn datastream(rng: &mut Rng, offset: usize) -> String {
+    let offset = offset.max(14);
+    let (prefix_letters, _) = LOWERCASE.split_at(13);
+
+    // first character of the marker is repeated just before it, so windows
+    // overlapping the marker and the prefix always have a duplicate
+    let mut marker = LOWERCASE.to_vec();
+    rng.shuffle(&mut marker);
+    marker.truncate(14);
+    let first = marker
+        .iter()
+        .position(|c| prefix_letters.contains(c))
+        .unwrap();
+    marker.swap(0, first);
+
+    let mut stream = (0..offset - 14)
+        .map(|_| rng.choose(prefix_letters))
+        .collect::<Vec<u8>>();
+    if let Some(last) = stream.last_mut() {
+        *last = marker[0];
+    }
+    stream.extend(&marker);
+    stream.extend((0..offset / 4).map(|_| rng.choose(LOWERCASE)));
+
+    String::from_utf8(stream).unwrap() + "\n"
+}
+
+struct Directory {
+    name: String,
+    files: Vec<(String, usize)>,
+    directories: Vec<Directory>,
+}
+
+impl Directory {
+    fn write_listing(&self, output: &mut String) {
+        output.push_str("$ ls\n");
+        for directory in &self.directories {
+            output.push_str(&format!("dir {}\n", directory.name));
+        }
+        for (name, size) in &self.files {
+            output.push_str(&format!("{size} {name}\n"));
+        }
+        for directory in &self.directories {
+            output.push_str(&format!("$ cd {}\n", directory.name));
+            directory.write_listing(output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+}
+
+/// Name that's not used yet in the directory.
+fn unique_name(rng: &mut Rng, used: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name = (0..rng.between(1, 8))
+            .map(|_| char::from(rng.choose(LOWERCASE)))
+            .collect::<String>();
+        if extension {
+            name.push('.');
+            name.push_str(rng.choose(&["txt", "dat", "log", "bin"]));
+        }
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn random_directory(rng: &mut Rng, name: String, directories: usize) -> Directory {
+    let mut used = HashSet::new();
+    let files = (0..rng.between(0, 4))
+        .map(|_| (unique_name(rng, &mut used, true), rng.between(1, 300_000)))
+        .collect();
+
+    // remaining directories are split randomly between the subdirectories
+    let mut remaining = directories;
+    let mut subdirectories = vec![];
+    while remaining > 0 {
+        let size = rng.between(1, remaining.min(remaining / 2 + 1));
+        remaining -= size;
+        let name = unique_name(rng, &mut used, false);
+        subdirectories.push(random_directory(rng, name, size - 1));
+    }
+
+    Directory {
+        name,
+        files,
+        directories: subdirectories,
+    }
+}
+
+fn terminal_transcript(rng: &mut Rng, directories: usize) -> String {
+    let root = random_directory(rng, String::from("/"), directories.saturating_sub(1));
+    let mut output = String::from("$ cd /\n");
+    root.write_listing(&mut output);
+    output
+}
+
+/// Generates a valid input of the day. The same day, size and seed always give the same input.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut Rng(seed);
+    let generator = match day {
+        1 => calories,
+        2 => strategy_guide,
+        3 => rucksacks,
+        4 => section_pairs,
+        5 => crane_procedure,
+        6 => datastream,
+        7 => terminal_transcript,
+        _ => return None,
+    };
+    Some(generator(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::Part;
+
+    use crate::{
+        days,
+        generate::{default_size, generate, DAYS},
+    };
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        for day in DAYS {
+            assert_eq!(generate(day, 50, 7), generate(day, 50, 7));
+            assert_ne!(generate(day, 50, 7), generate(day, 50, 8), "day {day:02}");
+        }
+        assert_eq!(generate(8, 50, 7), None);
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for day in DAYS {
+            let solve = days::find(day).unwrap().solve;
+            for seed in 0..5 {
+                for size in [1, default_size(day)] {
+                    let input = generate(day, size, seed).unwrap();
+                    if let Err(error) = solve(&input, &Part::ALL) {
+                        panic!("day {day:02}, size {size}, seed {seed}: {error}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rucksacks_have_single_shared_item_and_badge() {
+        let items = |text: &str| text.bytes().collect::<HashSet<u8>>();
+        let input = generate(3, 200, 3).unwrap();
+        let lines = input.lines().collect::<Vec<&str>>();
+
+        for line in &lines {
+            let (first, second) = line.split_at(line.len() / 2);
+            assert_eq!(
+                items(first).intersection(&items(second)).count(),
+                1,
+                "{line}"
+            );
+        }
+        for group in lines.chunks(3) {
+            let badges = items(group[0])
+                .intersection(&items(group[1]))
+                .filter(|item| items(group[2]).contains(item))
+                .count();
+            assert_eq!(badges, 1, "{group:?}");
+        }
+    }
+
+    #[test]
+    fn datastream_marker_is_at_requested_offset() {
+        let solve = days::find(6).unwrap().solve;
+        for offset in [14, 15, 100, 2800] {
+            let solved = solve(&generate(6, offset, 1).unwrap(), &[Part::Two]).unwrap();
+            assert_eq!(
+                solved.answer(Part::Two).unwrap().to_string(),
+                offset.to_string()
+            );
+        }
+    }
+}
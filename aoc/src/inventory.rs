@@ -0,0 +1,355 @@
+//! Day 01 inventory of the expedition, beyond the answers of the puzzle.
+
+use std::collections::BTreeMap;
+
+use aoc_2022_01::{
+    elf_name,
+    stats::{CalorieStats, ElfTotal},
+    total::Total,
+    CalorieLedger, Elf, TopElves,
+};
+use serde::Serialize;
+
+use crate::{
+    cli::OutputFormat,
+    output::{counted, AnswerValue},
+};
+
+/// Total in JSON, a string if it doesn't fit in `i64`, like answers.
+fn total_value(total: i128) -> AnswerValue {
+    i64::try_from(total).map_or_else(
+        |_| AnswerValue::Text(total.to_string()),
+        AnswerValue::Number,
+    )
+}
+
+/// Single elf, as listed by `aoc calories`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct ElfRecord {
+    pub elf: usize,
+    /// Id from the inventory, only CSV inventories have them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub items: Vec<i64>,
+    pub total: AnswerValue,
+}
+
+/// Renders every elf of the ledger, text has one line per elf, JSON is an array of elves.
+pub fn render_ledger<T: Total>(ledger: &CalorieLedger<T>, format: OutputFormat) -> String {
+    let records = ledger.elves().iter().map(|elf| ElfRecord {
+        elf: elf.index(),
+        id: elf.id().map(str::to_string),
+        items: elf.items().to_vec(),
+        total: total_value(elf.total().to_i128()),
+    });
+
+    match format {
+        OutputFormat::Text => records
+            .map(|record| {
+                format!(
+                    "Elf {}: {} calories in {}\n",
+                    elf_name(record.elf, record.id.as_deref()),
+                    record.total,
+                    counted(record.items.len(), "item")
+                )
+            })
+            .collect(),
+        // plain records, serializing them can't fail
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(&records.collect::<Vec<ElfRecord>>()).unwrap() + "\n"
+        }
+    }
+}
+
+/// Elf and their total, as ranked by `aoc calories --top`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct RankedRecord {
+    pub elf: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub total: AnswerValue,
+}
+
+/// Top elves in JSON, with elves tied with the last of them.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct TopRecord {
+    pub top: Vec<RankedRecord>,
+    pub tied: Vec<RankedRecord>,
+}
+
+fn ranked<T: Total>(elves: &[&Elf<T>]) -> Vec<RankedRecord> {
+    elves
+        .iter()
+        .map(|elf| RankedRecord {
+            elf: elf.index(),
+            id: elf.id().map(str::to_string),
+            total: total_value(elf.total().to_i128()),
+        })
+        .collect()
+}
+
+/// Renders the top elves, text has one numbered line per elf, followed by the tied ones.
+pub fn render_top<T: Total>(top: &TopElves<T>, format: OutputFormat) -> String {
+    let record = TopRecord {
+        top: ranked(&top.top),
+        tied: ranked(&top.tied),
+    };
+
+    match format {
+        OutputFormat::Text => {
+            let mut text = record
+                .top
+                .iter()
+                .enumerate()
+                .map(|(place, elf)| {
+                    let name = elf_name(elf.elf, elf.id.as_deref());
+                    format!("{}. Elf {name}: {} calories\n", place + 1, elf.total)
+                })
+                .collect::<String>();
+            if !record.tied.is_empty() {
+                let tied = record
+                    .tied
+                    .iter()
+                    .map(|elf| format!("Elf {}", elf_name(elf.elf, elf.id.as_deref())))
+                    .collect::<Vec<String>>();
+                text += &format!("Tied with the last place: {}\n", tied.join(", "));
+            }
+            text
+        }
+        // plain records, serializing them can't fail
+        OutputFormat::Json => serde_json::to_string_pretty(&record).unwrap() + "\n",
+    }
+}
+
+/// Statistics in JSON, see `CalorieStats`.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct StatsRecord {
+    pub elves: usize,
+    pub items: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Like `{"p10": 4000}`
+    pub percentiles: BTreeMap<String, AnswerValue>,
+    pub smallest: RankedRecord,
+    pub largest: RankedRecord,
+    pub item_counts: Vec<ItemCountRecord>,
+    pub largest_snack: SnackRecord,
+    pub histogram: Vec<BinRecord>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct ItemCountRecord {
+    pub items: usize,
+    pub elves: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct SnackRecord {
+    pub elf: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub calories: i64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct BinRecord {
+    pub start: AnswerValue,
+    pub end: AnswerValue,
+    pub elves: usize,
+}
+
+impl From<&CalorieStats> for StatsRecord {
+    fn from(stats: &CalorieStats) -> Self {
+        let ranked = |elf: &ElfTotal| RankedRecord {
+            elf: elf.elf,
+            id: elf.id.clone(),
+            total: total_value(elf.total),
+        };
+        StatsRecord {
+            elves: stats.elves,
+            items: stats.items,
+            mean: stats.mean,
+            median: stats.median,
+            percentiles: stats
+                .percentiles
+                .iter()
+                .map(|&(percentile, total)| (format!("p{percentile}"), total_value(total)))
+                .collect(),
+            smallest: ranked(&stats.smallest),
+            largest: ranked(&stats.largest),
+            item_counts: stats
+                .item_counts
+                .iter()
+                .map(|&(items, elves)| ItemCountRecord { items, elves })
+                .collect(),
+            largest_snack: SnackRecord {
+                elf: stats.largest_snack.elf,
+                id: stats.largest_snack.id.clone(),
+                calories: stats.largest_snack.calories,
+            },
+            histogram: stats
+                .histogram
+                .iter()
+                .map(|bin| BinRecord {
+                    start: total_value(bin.start),
+                    end: total_value(bin.end),
+                    elves: bin.elves,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Renders statistics of the ledger, `None` means there are no elves (`null` in JSON).
+pub fn render_stats(stats: Option<&CalorieStats>, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Text => stats.map_or_else(|| String::from("no elves\n"), ToString::to_string),
+        // plain records, serializing them can't fail
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(&stats.map(StatsRecord::from)).unwrap() + "\n"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "bigint")]
+    use aoc_2022_01::DayTotal;
+    use aoc_2022_01::{stats::CalorieStats, CalorieLedger, ParseMode};
+
+    use crate::{
+        cli::OutputFormat,
+        inventory::{render_ledger, render_stats, render_top},
+    };
+
+    #[test]
+    fn ledger_is_rendered_as_text_and_json() {
+        let ledger =
+            CalorieLedger::<i64>::parse("1000\n2000\n\n4000\n", ParseMode::Strict).unwrap();
+
+        assert_eq!(
+            render_ledger(&ledger, OutputFormat::Text),
+            "Elf 1: 3000 calories in 2 items\nElf 2: 4000 calories in 1 item\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_ledger(&ledger, OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"elf": 1, "items": [1000, 2000], "total": 3000},
+                {"elf": 2, "items": [4000], "total": 4000}
+            ])
+        );
+    }
+
+    #[test]
+    fn elves_are_named_by_their_ids() {
+        let ledger = CalorieLedger::<i64>::parse(
+            "elf_id,item_calories\nE7,1000\nE3,4000\n",
+            ParseMode::Strict,
+        )
+        .unwrap();
+
+        assert_eq!(
+            render_ledger(&ledger, OutputFormat::Text),
+            "Elf E7: 1000 calories in 1 item\nElf E3: 4000 calories in 1 item\n"
+        );
+        assert_eq!(
+            render_top(&ledger.top_n(1), OutputFormat::Text),
+            "1. Elf E3: 4000 calories\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_ledger(&ledger, OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"elf": 1, "id": "E7", "items": [1000], "total": 1000},
+                {"elf": 2, "id": "E3", "items": [4000], "total": 4000}
+            ])
+        );
+
+        let stats = CalorieStats::new(&ledger);
+        let json: serde_json::Value =
+            serde_json::from_str(&render_stats(stats.as_ref(), OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json["largest"],
+            serde_json::json!({"elf": 2, "id": "E3", "total": 4000})
+        );
+        assert_eq!(
+            json["largest_snack"],
+            serde_json::json!({"elf": 2, "id": "E3", "calories": 4000})
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn totals_too_large_for_numbers_are_rendered_as_text() {
+        let ledger =
+            CalorieLedger::<DayTotal>::parse("9223372036854775807\n1\n", ParseMode::Strict)
+                .unwrap();
+
+        assert_eq!(
+            render_ledger(&ledger, OutputFormat::Text),
+            "Elf 1: 9223372036854775808 calories in 2 items\n"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&render_top(&ledger.top_n(1), OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json["top"],
+            serde_json::json!([{"elf": 1, "total": "9223372036854775808"}])
+        );
+    }
+
+    #[test]
+    fn top_elves_are_rendered_with_ties() {
+        let ledger = CalorieLedger::<i64>::parse("3\n\n5\n\n3\n\n3\n", ParseMode::Strict).unwrap();
+        let top = ledger.top_n(2);
+
+        assert_eq!(
+            render_top(&top, OutputFormat::Text),
+            "1. Elf 2: 5 calories\n2. Elf 1: 3 calories\nTied with the last place: Elf 3, Elf 4\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_top(&top, OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "top": [{"elf": 2, "total": 5}, {"elf": 1, "total": 3}],
+                "tied": [{"elf": 3, "total": 3}, {"elf": 4, "total": 3}]
+            })
+        );
+    }
+
+    #[test]
+    fn stats_are_rendered_as_json() {
+        let ledger = CalorieLedger::<i64>::parse("5\n\n5\n1\n\n8\n", ParseMode::Strict).unwrap();
+        let stats = CalorieStats::new(&ledger);
+        let json: serde_json::Value =
+            serde_json::from_str(&render_stats(stats.as_ref(), OutputFormat::Json)).unwrap();
+
+        assert_eq!(json["elves"], 3);
+        assert_eq!(json["median"], 6.0);
+        assert_eq!(
+            json["percentiles"],
+            serde_json::json!({"p10": 5, "p25": 5, "p75": 8, "p90": 8})
+        );
+        assert_eq!(json["largest"], serde_json::json!({"elf": 3, "total": 8}));
+        assert_eq!(
+            json["item_counts"],
+            serde_json::json!([{"items": 1, "elves": 2}, {"items": 2, "elves": 1}])
+        );
+        assert_eq!(
+            json["histogram"],
+            serde_json::json!([
+                {"start": 5, "end": 6, "elves": 2},
+                {"start": 7, "end": 8, "elves": 1}
+            ])
+        );
+
+        assert_eq!(render_stats(None, OutputFormat::Json), "null\n");
+        assert_eq!(render_stats(None, OutputFormat::Text), "no elves\n");
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg(feature = "cli")]
+pub mod answers;
+pub mod bench;
+#[cfg(feature = "cli")]
+pub mod cli;
+pub mod days;
+pub mod examples;
+#[cfg(feature = "cli")]
+pub mod fetch;
+pub mod generate;
+#[cfg(feature = "cli")]
+pub mod inventory;
+#[cfg(feature = "cli")]
+pub mod logging;
+#[cfg(feature = "cli")]
+pub mod output;
+#[cfg(feature = "cli")]
+pub mod player;
+pub mod pool;
+pub mod scaffold;
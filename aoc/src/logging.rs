@@ -0,0 +1,33 @@
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+/// Environment variable with a tracing filter, like `debug` or `aoc_2022_05=trace`.
+pub const LOG_VARIABLE: &str = "AOC_LOG";
+
+/// Filter used for given number of `-v` flags.
+pub fn level_filter(verbosity: u8) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Sends logs to stderr, so they never mix with the answers.
+/// `-v` flags take precedence over `AOC_LOG`, which is used only when there are none.
+pub fn init(verbosity: u8) {
+    let filter = match (verbosity, EnvFilter::try_from_env(LOG_VARIABLE)) {
+        (0, Ok(filter)) => filter,
+        _ => EnvFilter::new(level_filter(verbosity)),
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        // closing spans report time spent in parsing and each part
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+}
@@ -0,0 +1,442 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, IsTerminal},
+    process::ExitCode,
+};
+
+use aoc::{
+    answers::{default_answers_path, KnownAnswers, Verdict},
+    bench,
+    cli::{
+        self, AnimateOptions, AnswersOptions, BenchOptions, CaloriesOptions, CheckOptions, Command,
+        FetchOptions, GenOptions, OutputFormat, RunOptions, Selection,
+    },
+    days::{self, Day, DaySelection, PartAnswer, Solved},
+    fetch::{default_config_path, default_throttle_path, FetchConfig, FetchOutcome, Fetcher},
+    generate, inventory, logging, output,
+    player::{Headless, Terminal},
+    pool::{self, Status},
+    scaffold,
+};
+use aoc_2022_01::{stats::CalorieStats, CalorieLedger, DayTotal, ParseMode};
+use aoc_common::{
+    animation::Screen,
+    check::Severity,
+    input::{workspace_dir, InputSource},
+    Part,
+};
+
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn select_days(selection: &Selection) -> Option<Vec<&'static Day>> {
+    days::select(selection.days)
+        .map_err(|error| eprintln!("error: {error}"))
+        .ok()
+}
+
+/// Solves the day, reporting errors on stderr.
+fn solve(day: &Day, parts: &[Part], input: Option<&str>) -> Option<Solved> {
+    let source = InputSource::for_day(day.number, input);
+    day.solve_from(&source, parts)
+        .map_err(|error| eprintln!("error: {error}"))
+        .ok()
+}
+
+/// Details of the day as a section of the output, if the day has any.
+fn details_section(day: &Day, input: Option<&str>) -> Option<String> {
+    let source = InputSource::for_day(day.number, input);
+    match source.read().and_then(|input| (day.details)(&input)) {
+        Ok(details) => {
+            details.map(|details| format!("Day {:02} details:\n{}", day.number, details.trim_end()))
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            None
+        }
+    }
+}
+
+fn run(options: RunOptions) -> ExitCode {
+    let Some(days) = select_days(&options.selection) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = options.selection.parts();
+
+    let input = options.input.as_deref();
+    let runs = pool::run_days(&days, options.jobs, |day| {
+        let source = InputSource::for_day(day.number, input);
+        if options.stream {
+            day.stream_from(&source, &parts)
+        } else {
+            day.solve_from(&source, &parts)
+        }
+    });
+
+    let mut records = vec![];
+    for run in &runs {
+        match &run.status {
+            Status::Solved(solved) => records.extend(output::records(solved)),
+            Status::Failed(error) => eprintln!("error: {error}"),
+            Status::Panicked(message) => eprintln!("error: day {:02} panicked: {message}", run.day),
+        }
+    }
+    let details = if options.details {
+        days.iter()
+            .filter_map(|day| details_section(day, input))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    // several days are summarized in a table, which already has the answers
+    if options.format == OutputFormat::Text && runs.len() > 1 {
+        print!("{}", pool::summary_table(&runs));
+    } else {
+        print!("{}", output::render(&records, options.format));
+    }
+    // details go to stderr in JSON mode, to keep the output parseable
+    for section in details {
+        match options.format {
+            OutputFormat::Text => println!("\n{section}"),
+            OutputFormat::Json => eprintln!("{section}"),
+        }
+    }
+
+    exit_code(runs.iter().all(pool::DayRun::is_success))
+}
+
+fn check(options: CheckOptions) -> ExitCode {
+    let Some(days) = select_days(&Selection {
+        days: options.days,
+        part: None,
+    }) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut all_read = true;
+    let mut checked = vec![];
+    for day in days {
+        let source = InputSource::for_day(day.number, options.input.as_deref());
+        match source.read() {
+            Ok(input) => checked.push((day.number, (day.check)(&input))),
+            Err(error) => {
+                eprintln!("error: {error}");
+                all_read = false;
+            }
+        }
+    }
+    print!("{}", output::render_problems(&checked, options.format));
+
+    // warnings alone don't fail the check
+    let no_errors = checked
+        .iter()
+        .flat_map(|(_, diagnostics)| diagnostics)
+        .all(|diagnostic| diagnostic.severity != Severity::Error);
+    exit_code(all_read && no_errors)
+}
+
+fn calories(options: CaloriesOptions) -> ExitCode {
+    let mode = match options.lenient {
+        true => ParseMode::Lenient,
+        false => ParseMode::Strict,
+    };
+    let source = InputSource::for_day(1, options.input.as_deref());
+    match source
+        .read()
+        .and_then(|input| CalorieLedger::<DayTotal>::parse_as(&input, options.input_format, mode))
+    {
+        Ok(ledger) => {
+            let output = match options.top {
+                Some(n) => inventory::render_top(&ledger.top_n(n), options.format),
+                None if options.stats => {
+                    inventory::render_stats(CalorieStats::new(&ledger).as_ref(), options.format)
+                }
+                None => inventory::render_ledger(&ledger, options.format),
+            };
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {}", error.for_day(1));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load_answers(options: &AnswersOptions) -> Option<(KnownAnswers, std::path::PathBuf)> {
+    let path = options.answers.clone().unwrap_or_else(default_answers_path);
+    KnownAnswers::load(&path)
+        .map(|answers| (answers, path))
+        .map_err(|error| eprintln!("error: {error}"))
+        .ok()
+}
+
+fn verify(options: AnswersOptions) -> ExitCode {
+    let Some((known_answers, _)) = load_answers(&options) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(days) = select_days(&options.selection) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = options.selection.parts();
+
+    let (mut correct, mut wrong, mut unknown, mut failed) = (0, 0, 0, 0);
+    for day in days {
+        let Some(solved) = solve(day, &parts, None) else {
+            failed += 1;
+            continue;
+        };
+
+        for PartAnswer { part, answer, .. } in solved.answers {
+            let prefix = format!("Day {:02} part {part}", day.number);
+            match known_answers.check(day.number, part, &answer) {
+                Verdict::Correct => {
+                    correct += 1;
+                    println!("{prefix}: ok ({answer})");
+                }
+                Verdict::Wrong { expected } => {
+                    wrong += 1;
+                    println!("{prefix}: MISMATCH, expected {expected}, got {answer}");
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    println!("{prefix}: no recorded answer ({answer})");
+                }
+            }
+        }
+    }
+
+    println!("{correct} correct, {wrong} mismatched, {unknown} not recorded, {failed} days failed");
+    exit_code(wrong == 0 && failed == 0)
+}
+
+fn record(options: AnswersOptions) -> ExitCode {
+    let Some((mut known_answers, path)) = load_answers(&options) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(days) = select_days(&options.selection) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = options.selection.parts();
+
+    let mut success = true;
+    for day in days {
+        let Some(solved) = solve(day, &parts, None) else {
+            success = false;
+            continue;
+        };
+
+        for PartAnswer { part, answer, .. } in solved.answers {
+            let prefix = format!("Day {:02} part {part}", day.number);
+            match known_answers.record(day.number, part, &answer) {
+                Some(previous) if previous != answer.to_string() => {
+                    println!("{prefix}: {answer} (was {previous})")
+                }
+                Some(_) => println!("{prefix}: {answer} (unchanged)"),
+                None => println!("{prefix}: {answer} (new)"),
+            }
+        }
+    }
+
+    if let Err(error) = known_answers.save(&path) {
+        eprintln!("error: cannot save answers to {}: {error}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    exit_code(success)
+}
+
+fn bench(options: BenchOptions) -> ExitCode {
+    let Some(days) = select_days(&options.selection) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = options.selection.parts();
+
+    let mut success = true;
+    let mut benchmarks = vec![];
+    for day in days {
+        let source = InputSource::for_day(day.number, options.input.as_deref());
+        let result = source
+            .read()
+            .and_then(|input| (day.bench)(&input, &parts, options.iterations));
+        match result {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(error) => {
+                eprintln!("error: {error}");
+                success = false;
+            }
+        }
+    }
+
+    match options.format {
+        OutputFormat::Text => print!("{}", bench::to_table(&benchmarks)),
+        // benchmark results contain only numbers and plain enums
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&benchmarks).unwrap()),
+    }
+
+    exit_code(success)
+}
+
+fn fetch(options: FetchOptions) -> ExitCode {
+    let config_path = options.config.unwrap_or_else(default_config_path);
+    let config = match FetchConfig::load(&config_path) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("error: {}: {error}", config_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let fetcher = Fetcher::new(config, default_throttle_path());
+
+    // inputs are usually fetched before the day is solved, so a single day doesn't have to be registered
+    let numbers = match options.days {
+        DaySelection::Single(number) => vec![number],
+        DaySelection::All => days::DAYS.iter().map(|day| day.number).collect(),
+    };
+
+    let mut success = true;
+    for number in numbers {
+        let destination = match InputSource::for_day(number, options.input.as_deref()) {
+            InputSource::File(path) => path,
+            InputSource::Stdin => {
+                eprintln!("error: cannot save the input to standard input");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        match fetcher.fetch(number, &destination) {
+            Ok(FetchOutcome::Cached) => {
+                println!("Day {number:02}: {} (cached)", destination.display())
+            }
+            Ok(FetchOutcome::Downloaded) => {
+                println!("Day {number:02}: {} (downloaded)", destination.display())
+            }
+            Err(error) => {
+                eprintln!("error: day {number:02}: {error}");
+                success = false;
+            }
+        }
+    }
+
+    exit_code(success)
+}
+
+fn new(day: u8) -> ExitCode {
+    let workspace = workspace_dir();
+    match scaffold::new_day(&workspace, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!(
+                    "{}",
+                    path.strip_prefix(&workspace).unwrap_or(&path).display()
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: cannot create day {day:02}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn gen(options: GenOptions) -> ExitCode {
+    let size = options
+        .size
+        .unwrap_or_else(|| generate::default_size(options.day));
+    let Some(input) = generate::generate(options.day, size, options.seed) else {
+        eprintln!(
+            "error: there's no input generator for day {:02}",
+            options.day
+        );
+        return ExitCode::FAILURE;
+    };
+
+    match options.output {
+        Some(path) => match std::fs::write(&path, input) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("error: cannot write {}: {error}", path.display());
+                ExitCode::FAILURE
+            }
+        },
+        None => {
+            print!("{input}");
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn animate(options: AnimateOptions) -> ExitCode {
+    let Some(days) = select_days(&Selection {
+        days: DaySelection::Single(options.day),
+        part: None,
+    }) else {
+        return ExitCode::FAILURE;
+    };
+    let day = days[0];
+
+    let mut screen: Box<dyn Screen> = match &options.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(Headless::new(BufWriter::new(file))),
+            Err(error) => {
+                eprintln!("error: cannot write {}: {error}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None if io::stdout().is_terminal() => Box::new(Terminal::new(options.delay)),
+        None => {
+            eprintln!("error: animation needs a terminal, use --output to write frames to a file");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = InputSource::for_day(day.number, options.input.as_deref());
+    match source
+        .read()
+        .and_then(|input| (day.animate)(&input, screen.as_mut()))
+    {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => {
+            eprintln!("error: day {:02} has no animation", day.number);
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let (verbosity, args) = cli::take_verbosity(env::args().skip(1));
+    logging::init(verbosity);
+
+    match cli::parse_args(args) {
+        Ok(Command::Run(options)) => run(options),
+        Ok(Command::Check(options)) => check(options),
+        Ok(Command::Calories(options)) => calories(options),
+        Ok(Command::Verify(options)) => verify(options),
+        Ok(Command::Record(options)) => record(options),
+        Ok(Command::Bench(options)) => bench(options),
+        Ok(Command::Fetch(options)) => fetch(options),
+        Ok(Command::New(day)) => new(day),
+        Ok(Command::Gen(options)) => gen(options),
+        Ok(Command::Animate(options)) => animate(options),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{}", cli::USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+
+use aoc_common::{
+    check::{Diagnostic, Severity},
+    Answer,
+};
+use serde::Serialize;
+
+use crate::{bench::format_duration, cli::OutputFormat, days::Solved};
+
+/// Answer as it appears in JSON, numbers stay numbers.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[serde(untagged)]
+pub enum AnswerValue {
+    Number(i64),
+    Text(String),
+}
+
+impl From<&Answer> for AnswerValue {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(number) => AnswerValue::Number(*number),
+            Answer::Text(text) => AnswerValue::Text(text.clone()),
+        }
+    }
+}
+
+impl fmt::Display for AnswerValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerValue::Number(number) => write!(f, "{number}"),
+            AnswerValue::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Times in nanoseconds. Parsing is shared by both parts of a day.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Timings {
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+/// Single answer, as reported by `aoc run`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: AnswerValue,
+    pub timings: Timings,
+}
+
+pub fn records(solved: &Solved) -> Vec<AnswerRecord> {
+    solved
+        .answers
+        .iter()
+        .map(|answer| AnswerRecord {
+            day: solved.day,
+            part: answer.part as u8,
+            answer: AnswerValue::from(&answer.answer),
+            timings: Timings {
+                parse_ns: solved.parse_time.as_nanos() as u64,
+                solve_ns: answer.elapsed.as_nanos() as u64,
+            },
+        })
+        .collect()
+}
+
+fn to_text(records: &[AnswerRecord]) -> String {
+    records
+        .iter()
+        .map(|record| {
+            let answer = match &record.answer {
+                AnswerValue::Number(number) => number.to_string(),
+                AnswerValue::Text(text) => text.clone(),
+            };
+            format!(
+                "Day {:02} part {}: {answer} (parse {}, solve {})\n",
+                record.day,
+                record.part,
+                format_duration(record.timings.parse_ns),
+                format_duration(record.timings.solve_ns)
+            )
+        })
+        .collect()
+}
+
+/// Renders the records in given format, text has one line per record,
+/// JSON is an array of records.
+pub fn render(records: &[AnswerRecord], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Text => to_text(records),
+        // records contain only numbers and strings
+        OutputFormat::Json => serde_json::to_string_pretty(records).unwrap() + "\n",
+    }
+}
+
+/// Problem with the input, as reported by `aoc check`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct ProblemRecord {
+    pub day: u8,
+    pub severity: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+/// Amount of things, like `1 error` or `3 errors`.
+pub fn counted(amount: usize, what: &str) -> String {
+    match amount {
+        1 => format!("1 {what}"),
+        _ => format!("{amount} {what}s"),
+    }
+}
+
+/// Renders problems found in inputs of given days. Text has every problem with the offending line,
+/// followed by a summary line for every day. JSON is an array of problems of all days.
+pub fn render_problems(checked: &[(u8, Vec<Diagnostic>)], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Text => {
+            let mut text = String::new();
+            for diagnostic in checked.iter().flat_map(|(_, diagnostics)| diagnostics) {
+                text += &format!("{diagnostic}\n\n");
+            }
+            for (day, diagnostics) in checked {
+                let errors = diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity == Severity::Error)
+                    .count();
+                let warnings = diagnostics.len() - errors;
+                text += &match diagnostics.len() {
+                    0 => format!("Day {day:02}: no problems\n"),
+                    _ => format!(
+                        "Day {day:02}: {}, {}\n",
+                        counted(errors, "error"),
+                        counted(warnings, "warning")
+                    ),
+                };
+            }
+            text
+        }
+        OutputFormat::Json => {
+            let records = checked
+                .iter()
+                .flat_map(|(day, diagnostics)| {
+                    diagnostics.iter().map(|diagnostic| ProblemRecord {
+                        day: *day,
+                        severity: diagnostic.severity.to_string(),
+                        line: diagnostic.problem.line,
+                        column: diagnostic.problem.column,
+                        message: diagnostic.problem.message.clone(),
+                    })
+                })
+                .collect::<Vec<ProblemRecord>>();
+            // records contain only numbers and strings
+            serde_json::to_string_pretty(&records).unwrap() + "\n"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::{check::Report, error::ParseError, Answer, Part};
+
+    use crate::{
+        cli::OutputFormat,
+        days::{PartAnswer, Solved},
+        output::{records, render, render_problems},
+    };
+
+    fn solved() -> Solved {
+        Solved {
+            day: 5,
+            parse_time: Duration::from_micros(20),
+            answers: vec![
+                PartAnswer {
+                    part: Part::One,
+                    answer: Answer::from("CMZ"),
+                    elapsed: Duration::from_nanos(300),
+                },
+                PartAnswer {
+                    part: Part::Two,
+                    answer: Answer::from(12),
+                    elapsed: Duration::from_nanos(450),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn records_are_rendered_as_text() {
+        assert_eq!(
+            render(&records(&solved()), OutputFormat::Text),
+            "Day 05 part 1: CMZ (parse 20.00 µs, solve 300 ns)\n\
+             Day 05 part 2: 12 (parse 20.00 µs, solve 450 ns)\n"
+        );
+    }
+
+    #[test]
+    fn records_are_rendered_as_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&records(&solved()), OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"day": 5, "part": 1, "answer": "CMZ", "timings": {"parse_ns": 20000, "solve_ns": 300}},
+                {"day": 5, "part": 2, "answer": 12, "timings": {"parse_ns": 20000, "solve_ns": 450}}
+            ])
+        );
+    }
+
+    #[test]
+    fn problems_are_rendered_with_summary() {
+        let mut report = Report::default();
+        report.error(
+            ParseError::new("unknown response 'Q'")
+                .at_column(3)
+                .at_line(2, "A Q")
+                .for_day(2),
+        );
+        report.warning(ParseError::new("odd").for_day(2));
+        let checked = [(1, vec![]), (2, report.into_diagnostics())];
+
+        assert_eq!(
+            render_problems(&checked, OutputFormat::Text),
+            "error: day 02, line 2, column 3: unknown response 'Q'\n2 | A Q\n  |   ^\n\n\
+             warning: day 02: odd\n\n\
+             Day 01: no problems\n\
+             Day 02: 1 error, 1 warning\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_problems(&checked, OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"day": 2, "severity": "error", "line": 2, "column": 3, "message": "unknown response 'Q'"},
+                {"day": 2, "severity": "warning", "line": null, "column": null, "message": "odd"}
+            ])
+        );
+    }
+}
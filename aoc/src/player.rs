@@ -0,0 +1,386 @@
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use aoc_common::animation::{Animation, Frame, Screen};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal,
+};
+
+/// Time every frame is shown for, unless changed with `--delay` or keys.
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+const MIN_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+pub const KEYS_HELP: &str = "space: pause, n/→: step, +/-: speed, q: quit";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Action {
+    TogglePause,
+    /// Shows the next frame and pauses
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Action {
+    pub fn for_key(key: KeyEvent) -> Option<Action> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Char('p') => Some(Action::TogglePause),
+            KeyCode::Char('n') | KeyCode::Right => Some(Action::Step),
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Action::Faster),
+            KeyCode::Char('-') | KeyCode::Down => Some(Action::Slower),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::Quit)
+            }
+            KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// State of the playback, changed by the viewer's actions.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Playback {
+    pub delay: Duration,
+    pub paused: bool,
+    pub quit: bool,
+}
+
+impl Playback {
+    pub fn new(delay: Duration) -> Self {
+        Playback {
+            delay: delay.clamp(MIN_DELAY, MAX_DELAY),
+            paused: false,
+            quit: false,
+        }
+    }
+
+    /// Applies the action, returns whether the next frame should be shown right away.
+    pub fn apply(&mut self, action: Action) -> bool {
+        match action {
+            Action::TogglePause => {
+                self.paused = !self.paused;
+                !self.paused
+            }
+            Action::Step => {
+                self.paused = true;
+                true
+            }
+            Action::Faster => {
+                self.delay = (self.delay / 2).max(MIN_DELAY);
+                false
+            }
+            Action::Slower => {
+                self.delay = (self.delay * 2).min(MAX_DELAY);
+                false
+            }
+            Action::Quit => {
+                self.quit = true;
+                true
+            }
+        }
+    }
+
+    fn status(&self, frame_number: usize, finished: bool) -> String {
+        let state = match (finished, self.paused) {
+            (true, _) => "finished",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+        format!(
+            "frame {frame_number} | {state} | {} ms per frame | {KEYS_HELP}",
+            self.delay.as_millis()
+        )
+    }
+}
+
+/// Plays the animation in the alternate screen of the terminal, with a frame every `delay`.
+pub struct Terminal {
+    playback: Playback,
+}
+
+impl Terminal {
+    pub fn new(delay: Duration) -> Self {
+        Terminal {
+            playback: Playback::new(delay),
+        }
+    }
+
+    fn play_frames(&mut self, out: &mut impl Write, frames: Animation<'_>) -> io::Result<()> {
+        let mut last = None;
+        for (index, frame) in frames.enumerate() {
+            let number = index + 1;
+            draw(out, &frame, number, &self.playback, false)?;
+            wait(&mut self.playback, |playback| {
+                draw(out, &frame, number, playback, false)
+            })?;
+            if self.playback.quit {
+                return Ok(());
+            }
+            last = Some((number, frame));
+        }
+
+        // the last frame stays until the viewer leaves
+        if let Some((number, frame)) = last {
+            draw(out, &frame, number, &self.playback, true)?;
+            while !matches!(Action::for_key(next_key()?), Some(Action::Quit)) {}
+        }
+        Ok(())
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    frame: &Frame,
+    number: usize,
+    playback: &Playback,
+    finished: bool,
+) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let (width, height) = (width as usize, height as usize);
+    queue!(
+        out,
+        terminal::Clear(terminal::ClearType::All),
+        cursor::MoveTo(0, 0),
+        SetAttribute(Attribute::Bold),
+        Print(truncate(&frame.caption, width)),
+        SetAttribute(Attribute::Reset)
+    )?;
+
+    // caption on top, status at the bottom, lines that don't fit are cut
+    for (index, line) in frame
+        .lines
+        .iter()
+        .enumerate()
+        .take(height.saturating_sub(3))
+    {
+        queue!(out, cursor::MoveTo(0, index as u16 + 2))?;
+        for (column, character) in line.chars().take(width).enumerate() {
+            if frame.is_highlighted(index, column) {
+                queue!(
+                    out,
+                    SetAttribute(Attribute::Reverse),
+                    Print(character),
+                    SetAttribute(Attribute::NoReverse)
+                )?;
+            } else {
+                queue!(out, Print(character))?;
+            }
+        }
+    }
+
+    queue!(
+        out,
+        cursor::MoveTo(0, height.saturating_sub(1) as u16),
+        SetAttribute(Attribute::Dim),
+        Print(truncate(&playback.status(number, finished), width)),
+        SetAttribute(Attribute::Reset)
+    )?;
+    out.flush()
+}
+
+/// Waits for the time of the frame to pass, or for an action that skips it.
+fn wait(
+    playback: &mut Playback,
+    mut redraw: impl FnMut(&Playback) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut deadline = Instant::now() + playback.delay;
+    loop {
+        let timeout = if playback.paused {
+            MAX_DELAY
+        } else {
+            deadline.saturating_duration_since(Instant::now())
+        };
+        if !playback.paused && timeout.is_zero() {
+            return Ok(());
+        }
+        if !event::poll(timeout)? {
+            continue;
+        }
+        let key = match event::read()? {
+            Event::Key(key) => key,
+            Event::Resize(..) => {
+                redraw(playback)?;
+                continue;
+            }
+            _ => continue,
+        };
+        let Some(action) = Action::for_key(key) else {
+            continue;
+        };
+        if playback.apply(action) {
+            return Ok(());
+        }
+        // faster or slower playback, or paused - the frame stays
+        deadline = Instant::now() + playback.delay;
+        redraw(playback)?;
+    }
+}
+
+fn next_key() -> io::Result<KeyEvent> {
+    loop {
+        if let Event::Key(key) = event::read()? {
+            return Ok(key);
+        }
+    }
+}
+
+impl Screen for Terminal {
+    fn play(&mut self, frames: Animation<'_>) -> io::Result<()> {
+        let mut out = io::stdout();
+        terminal::enable_raw_mode()?;
+        queue!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        let result = self.play_frames(&mut out, frames);
+
+        // the terminal is restored even if drawing failed
+        let restored = queue!(out, cursor::Show, terminal::LeaveAlternateScreen)
+            .and_then(|_| out.flush())
+            .and_then(|_| terminal::disable_raw_mode());
+        result.and(restored)
+    }
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
+
+/// Writes all frames one after another as plain text, with highlighted characters marked
+/// with `^` in a line below:
+///
+/// ```text
+/// frame 1: CrateMover 9000: move 1 from 2 to 1 (1/4), top crates: DCP
+/// [D]
+/// ^^^
+/// [N] [C]
+/// ```
+pub struct Headless<W> {
+    out: W,
+}
+
+impl<W: Write> Headless<W> {
+    pub fn new(out: W) -> Self {
+        Headless { out }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> Screen for Headless<W> {
+    fn play(&mut self, frames: Animation<'_>) -> io::Result<()> {
+        for (index, frame) in frames.enumerate() {
+            if index > 0 {
+                writeln!(self.out)?;
+            }
+            writeln!(self.out, "frame {}: {}", index + 1, frame.caption)?;
+            for (number, line) in frame.lines.iter().enumerate() {
+                writeln!(self.out, "{line}")?;
+                let end = frame
+                    .highlights
+                    .iter()
+                    .filter(|highlight| highlight.line == number)
+                    .map(|highlight| highlight.columns.end)
+                    .max();
+                if let Some(end) = end {
+                    let marks = (0..end)
+                        .map(|column| match frame.is_highlighted(number, column) {
+                            true => '^',
+                            false => ' ',
+                        })
+                        .collect::<String>();
+                    writeln!(self.out, "{}", marks.trim_end())?;
+                }
+            }
+        }
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::animation::{Frame, Screen};
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+    use crate::player::{Action, Headless, Playback};
+
+    #[test]
+    fn keys_are_mapped_to_actions() {
+        let keys = [
+            KeyEvent::from(KeyCode::Char(' ')),
+            KeyEvent::from(KeyCode::Right),
+            KeyEvent::from(KeyCode::Char('+')),
+            KeyEvent::from(KeyCode::Char('-')),
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            KeyEvent::from(KeyCode::Char('x')),
+        ];
+        let expected_actions = [
+            Some(Action::TogglePause),
+            Some(Action::Step),
+            Some(Action::Faster),
+            Some(Action::Slower),
+            Some(Action::Quit),
+            None,
+        ];
+
+        for (key, action) in keys.into_iter().zip(expected_actions) {
+            assert_eq!(Action::for_key(key), action, "{key:?}");
+        }
+
+        let mut released = KeyEvent::from(KeyCode::Char('q'));
+        released.kind = KeyEventKind::Release;
+        assert_eq!(Action::for_key(released), None);
+    }
+
+    #[test]
+    fn playback_is_controlled_by_actions() {
+        let mut playback = Playback::new(Duration::from_millis(100));
+
+        assert!(!playback.apply(Action::TogglePause));
+        assert!(playback.paused);
+        // stepping shows the next frame, but stays paused
+        assert!(playback.apply(Action::Step));
+        assert!(playback.paused);
+        assert!(playback.apply(Action::TogglePause));
+        assert!(!playback.paused);
+
+        assert!(!playback.apply(Action::Faster));
+        assert_eq!(playback.delay, Duration::from_millis(50));
+        for _ in 0..20 {
+            playback.apply(Action::Slower);
+        }
+        assert_eq!(playback.delay, Duration::from_secs(5));
+
+        assert!(playback.apply(Action::Quit));
+        assert!(playback.quit);
+    }
+
+    #[test]
+    fn headless_frames_mark_highlights() {
+        let frames = vec![
+            Frame::new("start", "[A]\n[B] [C]"),
+            Frame::new("move", "[A] [B]\n    [C]")
+                .highlight(0, 4..7)
+                .highlight(1, 4..7),
+        ];
+        let mut screen = Headless::new(vec![]);
+        screen.play(Box::new(frames.into_iter())).unwrap();
+
+        assert_eq!(
+            String::from_utf8(screen.into_inner()).unwrap(),
+            "frame 1: start\n[A]\n[B] [C]\n\nframe 2: move\n[A] [B]\n    ^^^\n    [C]\n    ^^^\n"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Result;
+
+use crate::{
+    bench::format_duration,
+    days::{Day, Solved},
+};
+
+#[derive(Debug)]
+pub enum Status {
+    Solved(Solved),
+    Failed(String),
+    Panicked(String),
+}
+
+/// Outcome of solving a single day, with the wall time it took.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+impl DayRun {
+    pub fn is_success(&self) -> bool {
+        matches!(self.status, Status::Solved(_))
+    }
+}
+
+/// Text of the panic, if it was raised with one.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => String::from("unknown panic"),
+        },
+    }
+}
+
+fn run_day(day: &Day, solve: &(impl Fn(&Day) -> Result<Solved> + Sync)) -> DayRun {
+    let start = Instant::now();
+    let status = match panic::catch_unwind(AssertUnwindSafe(|| solve(day))) {
+        Ok(Ok(solved)) => Status::Solved(solved),
+        Ok(Err(error)) => Status::Failed(error.to_string()),
+        Err(payload) => Status::Panicked(panic_message(payload)),
+    };
+
+    DayRun {
+        day: day.number,
+        status,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Solves the days on `jobs` threads. A panic in one day is reported as its status
+/// and doesn't affect the others. Results are in the same order as `days`.
+pub fn run_days(
+    days: &[&Day],
+    jobs: usize,
+    solve: impl Fn(&Day) -> Result<Solved> + Sync,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..days.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(index) else {
+                    break;
+                };
+                let run = run_day(day, &solve);
+                results.lock().unwrap()[index] = Some(run);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        // every index was taken by some worker, and workers don't panic outside of `run_day`
+        .map(Option::unwrap)
+        .collect()
+}
+
+/// Renders a table with a row for every answer, or a single row for a day that wasn't solved.
+pub fn summary_table(runs: &[DayRun]) -> String {
+    let row = |day: u8, part: &str, answer: &str, status: &str, elapsed: Duration| {
+        format!(
+            "{:<4} {:<5} {:<16} {:<9} {:>10}\n",
+            format!("{day:02}"),
+            part,
+            answer,
+            status,
+            format_duration(elapsed.as_nanos() as u64)
+        )
+    };
+
+    let mut table = format!(
+        "{:<4} {:<5} {:<16} {:<9} {:>10}\n",
+        "day", "part", "answer", "status", "elapsed"
+    );
+    for run in runs {
+        match &run.status {
+            Status::Solved(solved) => {
+                for answer in &solved.answers {
+                    table += &row(
+                        run.day,
+                        &answer.part.to_string(),
+                        &answer.answer.to_string(),
+                        "ok",
+                        solved.parse_time + answer.elapsed,
+                    );
+                }
+            }
+            Status::Failed(_) => table += &row(run.day, "-", "-", "failed", run.elapsed),
+            Status::Panicked(_) => table += &row(run.day, "-", "-", "panicked", run.elapsed),
+        }
+    }
+
+    let failed = runs.iter().filter(|run| !run.is_success()).count();
+    table += &format!("{} days solved, {failed} failed\n", runs.len() - failed);
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::{Answer, Error, Part, Result, Solution};
+
+    use crate::{
+        bench,
+        days::{Day, PartAnswer, Solved},
+        pool::{run_days, summary_table, DayRun, Status},
+    };
+
+    struct Working;
+
+    impl Solution for Working {
+        const DAY: u8 = 1;
+
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part1(input: &usize) -> Answer {
+            (*input).into()
+        }
+
+        fn part2(input: &usize) -> Answer {
+            (*input * 2).into()
+        }
+    }
+
+    fn fake_day(number: u8) -> Day {
+        Day {
+            number,
+            solve: |_, _| Err(Error::parse("unused")),
+            stream: None,
+            details: |_| Ok(None),
+            check: |_| vec![],
+            animate: |_, _| Ok(false),
+            bench: bench::benchmark::<Working>,
+        }
+    }
+
+    #[test]
+    fn panicking_day_does_not_stop_others() {
+        let days = (1..=6).map(fake_day).collect::<Vec<Day>>();
+        let days = days.iter().collect::<Vec<&Day>>();
+
+        let runs = run_days(&days, 3, |day| match day.number {
+            2 => panic!("day {} is not finished", day.number),
+            4 => Err(Error::parse("bad input")),
+            number => Ok(Solved {
+                day: number,
+                parse_time: Duration::ZERO,
+                answers: vec![],
+            }),
+        });
+
+        assert_eq!(
+            runs.iter().map(|run| run.day).collect::<Vec<u8>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            runs.iter().map(DayRun::is_success).collect::<Vec<bool>>(),
+            vec![true, false, true, false, true, true]
+        );
+        match &runs[1].status {
+            Status::Panicked(message) => assert_eq!(message, "day 2 is not finished"),
+            other => panic!("expected panic, got {other:?}"),
+        }
+        match &runs[3].status {
+            Status::Failed(message) => assert_eq!(message, "parse error, bad input"),
+            other => panic!("expected failure, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn summary_shows_answers_and_failures() {
+        let runs = [
+            DayRun {
+                day: 1,
+                status: Status::Solved(Solved {
+                    day: 1,
+                    parse_time: Duration::from_nanos(100),
+                    answers: vec![PartAnswer {
+                        part: Part::Two,
+                        answer: Answer::from(45000),
+                        elapsed: Duration::from_nanos(20),
+                    }],
+                }),
+                elapsed: Duration::from_nanos(150),
+            },
+            DayRun {
+                day: 7,
+                status: Status::Panicked(String::from("oops")),
+                elapsed: Duration::from_nanos(900),
+            },
+        ];
+
+        assert_eq!(
+            summary_table(&runs),
+            "day  part  answer           status       elapsed\n\
+             01   2     45000            ok            120 ns\n\
+             07   -     -                panicked      900 ns\n\
+             1 days solved, 1 failed\n"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const TEMPLATE_DIR: &str = "template";
+
+/// Parts of the template copied to a new day. Anything else in it, like `target/` or
+/// `Cargo.lock` left by building the template on its own, is ignored.
+pub const TEMPLATE_PARTS: [&str; 3] = ["Cargo.toml", "src", "examples"];
+
+/// Replaces the placeholders used in template files with names for the day.
+fn fill_placeholders(text: &str, day: u8) -> String {
+    text.replace("aoc_2022_XX", &format!("aoc_2022_{day:02}"))
+        .replace("DayXX", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+/// Files of every part of the template as (path relative to the template, contents for the day).
+fn render_template(template_dir: &Path, day: u8) -> io::Result<Vec<(PathBuf, String)>> {
+    let mut files = vec![];
+    for part in TEMPLATE_PARTS {
+        let path = PathBuf::from(part);
+        if template_dir.join(&path).is_dir() {
+            render_dir(template_dir, &path, day, &mut files)?;
+        } else {
+            let text = fs::read_to_string(template_dir.join(&path))?;
+            files.push((path, fill_placeholders(&text, day)));
+        }
+    }
+    Ok(files)
+}
+
+/// Files of the directory at `relative` in the template, and of all directories in it.
+fn render_dir(
+    template_dir: &Path,
+    relative: &Path,
+    day: u8,
+    files: &mut Vec<(PathBuf, String)>,
+) -> io::Result<()> {
+    let mut entries = fs::read_dir(template_dir.join(relative))?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for name in entries {
+        let path = relative.join(name);
+        if template_dir.join(&path).is_dir() {
+            render_dir(template_dir, &path, day, files)?;
+        } else {
+            let text = fs::read_to_string(template_dir.join(&path))?;
+            files.push((path, fill_placeholders(&text, day)));
+        }
+    }
+    Ok(())
+}
+
+fn malformed(path: &Path, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("cannot register the day in {}: {message}", path.display()),
+    )
+}
+
+/// Number of the day in `line`, if it starts with `prefix` followed by the number.
+fn registered_day(line: &str, prefix: &str) -> Option<u8> {
+    line.strip_prefix(prefix)?.get(..2)?.parse().ok()
+}
+
+/// Inserts `new_line` among the lines of other days, keeping them ordered.
+/// Lines of days start like `new_line` up to `aoc_2022_`, other lines may mention days too,
+/// like features forwarded to them.
+fn insert_day_line(path: &Path, text: &str, day: u8, new_line: &str) -> io::Result<String> {
+    let prefix = new_line
+        .find("aoc_2022_")
+        .map_or(new_line, |start| &new_line[..start + "aoc_2022_".len()]);
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    let registered = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| registered_day(line, prefix).map(|number| (index, number)))
+        .collect::<Vec<(usize, u8)>>();
+
+    if registered.iter().any(|&(_, number)| number == day) {
+        return Err(malformed(
+            path,
+            &format!("day {day:02} is already registered"),
+        ));
+    }
+    let position = match registered.iter().rfind(|&&(_, number)| number < day) {
+        Some(&(index, _)) => index + 1,
+        None => match registered.first() {
+            Some(&(index, _)) => index,
+            None => {
+                return Err(malformed(
+                    path,
+                    "no registered days to put the new one next to",
+                ))
+            }
+        },
+    };
+
+    lines.insert(position, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day's directory to `members` of the workspace manifest.
+fn add_workspace_member(path: &Path, text: &str, day: u8) -> io::Result<String> {
+    let member = format!("{day:02}");
+    let mut found = false;
+    let lines = text
+        .lines()
+        .map(|line| {
+            let Some(list) = line
+                .strip_prefix("members = [")
+                .and_then(|rest| rest.strip_suffix(']'))
+            else {
+                return Ok(line.to_string());
+            };
+            found = true;
+
+            let mut members = list
+                .split(',')
+                .map(|member| member.trim().trim_matches('"'))
+                .filter(|member| !member.is_empty())
+                .collect::<Vec<&str>>();
+            if members.contains(&member.as_str()) {
+                return Err(malformed(path, &format!("{member} is already a member")));
+            }
+            members.push(&member);
+            // days after everything else, in order
+            members.sort_by_key(|member| (member.parse::<u8>().is_ok(), member.to_string()));
+
+            let members = members
+                .iter()
+                .map(|member| format!("\"{member}\""))
+                .collect::<Vec<String>>();
+            Ok(format!("members = [{}]", members.join(", ")))
+        })
+        .collect::<io::Result<Vec<String>>>()?;
+
+    if !found {
+        return Err(malformed(path, "no single-line `members = [...]` list"));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Path `path` is written to before it's moved in place.
+fn staged(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".new");
+    PathBuf::from(name)
+}
+
+/// Creates the crate of `day` in `workspace` from the template, and registers it
+/// in the workspace manifest, the runner's manifest and the list of days.
+/// The day's directory may already exist, for example with an input downloaded by `aoc fetch`,
+/// but nothing is done if the crate is already there or the day is already registered.
+/// Every file is first written next to its destination and moved in place once all of them
+/// are written, so a failure while writing leaves the workspace as it was.
+/// Returns paths of all created and modified files.
+pub fn new_day(workspace: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let day_dir = workspace.join(format!("{day:02}"));
+    if let Some(existing) = TEMPLATE_PARTS
+        .iter()
+        .map(|part| day_dir.join(part))
+        .find(|path| path.exists())
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", existing.display()),
+        ));
+    }
+
+    let files = render_template(&workspace.join(TEMPLATE_DIR), day)?;
+
+    // prepare every change first, so a failure doesn't leave the workspace half-updated
+    let workspace_manifest = workspace.join("Cargo.toml");
+    let runner_manifest = workspace.join("aoc").join("Cargo.toml");
+    let days_list = workspace.join("aoc").join("src").join("days.rs");
+    let updates = [
+        (
+            workspace_manifest.clone(),
+            add_workspace_member(
+                &workspace_manifest,
+                &fs::read_to_string(&workspace_manifest)?,
+                day,
+            )?,
+        ),
+        (
+            runner_manifest.clone(),
+            insert_day_line(
+                &runner_manifest,
+                &fs::read_to_string(&runner_manifest)?,
+                day,
+                &format!("aoc_2022_{day:02} = {{ path = \"../{day:02}\" }}"),
+            )?,
+        ),
+        (
+            days_list.clone(),
+            insert_day_line(
+                &days_list,
+                &fs::read_to_string(&days_list)?,
+                day,
+                &format!("    day!(aoc_2022_{day:02}::Day{day:02}),"),
+            )?,
+        ),
+    ];
+
+    let day_dir_existed = day_dir.exists();
+    let writes = files
+        .into_iter()
+        .map(|(relative, text)| (day_dir.join(relative), text))
+        .chain(updates)
+        .collect::<Vec<(PathBuf, String)>>();
+    let staging = writes.iter().try_for_each(|(path, text)| {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(staged(path), text)
+    });
+    if let Err(error) = staging {
+        // cleanup is best effort, the error of writing is the one worth reporting
+        for (path, _) in &writes {
+            let _ = fs::remove_file(staged(path));
+        }
+        for part in TEMPLATE_PARTS {
+            let _ = fs::remove_dir_all(day_dir.join(part));
+        }
+        if !day_dir_existed {
+            let _ = fs::remove_dir(&day_dir);
+        }
+        return Err(error);
+    }
+
+    let mut written = vec![];
+    for (path, _) in writes {
+        fs::rename(staged(&path), &path)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::scaffold::{add_workspace_member, fill_placeholders, insert_day_line};
+
+    #[test]
+    fn placeholders_are_filled() {
+        assert_eq!(
+            fill_placeholders("name = \"aoc_2022_XX\"\nconst DAY: u8 = 0;\nDayXX", 8),
+            "name = \"aoc_2022_08\"\nconst DAY: u8 = 8;\nDay08"
+        );
+    }
+
+    #[test]
+    fn day_lines_are_inserted_in_order() {
+        let text =
+            "[dependencies]\naoc_common = {}\naoc_2022_01 = {}\naoc_2022_03 = {}\nserde = {}\n";
+        let path = Path::new("Cargo.toml");
+
+        assert_eq!(
+            insert_day_line(path, text, 2, "aoc_2022_02 = {}").unwrap(),
+            "[dependencies]\naoc_common = {}\naoc_2022_01 = {}\naoc_2022_02 = {}\naoc_2022_03 = {}\nserde = {}\n"
+        );
+        assert_eq!(
+            insert_day_line(path, text, 25, "aoc_2022_25 = {}").unwrap(),
+            "[dependencies]\naoc_common = {}\naoc_2022_01 = {}\naoc_2022_03 = {}\naoc_2022_25 = {}\nserde = {}\n"
+        );
+        assert!(insert_day_line(path, text, 3, "aoc_2022_03 = {}").is_err());
+        assert!(insert_day_line(path, "[dependencies]\n", 3, "aoc_2022_03 = {}").is_err());
+    }
+
+    #[test]
+    fn lines_only_mentioning_days_are_not_days() {
+        let text =
+            "[dependencies]\naoc_2022_01 = {}\n\n[features]\nbigint = [\"aoc_2022_01/bigint\"]\n";
+        let path = Path::new("Cargo.toml");
+
+        assert_eq!(
+            insert_day_line(path, text, 2, "aoc_2022_02 = {}").unwrap(),
+            "[dependencies]\naoc_2022_01 = {}\naoc_2022_02 = {}\n\n[features]\nbigint = [\"aoc_2022_01/bigint\"]\n"
+        );
+        assert_eq!(
+            insert_day_line(
+                Path::new("days.rs"),
+                "    day!(aoc_2022_01::Day01, streaming),\n",
+                2,
+                "    day!(aoc_2022_02::Day02),"
+            )
+            .unwrap(),
+            "    day!(aoc_2022_01::Day01, streaming),\n    day!(aoc_2022_02::Day02),\n"
+        );
+    }
+
+    #[test]
+    fn workspace_member_is_added() {
+        let path = Path::new("Cargo.toml");
+        let text = "[workspace]\nmembers = [\"aoc\", \"01\", \"10\"]\n";
+
+        assert_eq!(
+            add_workspace_member(path, text, 9).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"01\", \"09\", \"10\"]\n"
+        );
+        assert!(add_workspace_member(path, text, 10).is_err());
+        assert!(add_workspace_member(path, "[workspace]\n", 9).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "cli")]
+
+use aoc::{days::find, examples::load_examples, player::Headless};
+
+fn animate(day: u8) -> (bool, String) {
+    let day = find(day).unwrap();
+    let example = load_examples(day.number).unwrap().remove(0);
+    let mut screen = Headless::new(vec![]);
+    let animated = (day.animate)(&example.input, &mut screen).unwrap();
+    (animated, String::from_utf8(screen.into_inner()).unwrap())
+}
+
+#[test]
+fn crane_moves_are_written_headless() {
+    let (animated, frames) = animate(5);
+
+    assert!(animated);
+    assert!(frames.starts_with(
+        "frame 1: CrateMover 9000: starting arrangement\n    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n\n\
+         frame 2: CrateMover 9000: move 1 from 2 to 1 (1/4), top crates: DCP\n[D]\n^^^\n[N] [C]\n"
+    ));
+    assert!(frames.ends_with(
+        "frame 10: CrateMover 9001: move 1 from 1 to 2 (4/4), top crates: MCD\n\
+         \x20       [D]\n        [N]\n        [Z]\n[M] [C] [P]\n    ^^^\n 1   2   3 \n"
+    ));
+}
+
+#[test]
+fn sliding_window_is_written_headless() {
+    let (animated, frames) = animate(6);
+
+    assert!(animated);
+    assert!(frames.starts_with(
+        "frame 1: start-of-packet marker, characters 1-4: some characters repeat\n\
+         mjqjpqmgbljsphdztnvjfqwrcgsmlb\n^^^^\n"
+    ));
+}
+
+#[test]
+fn days_without_animation_are_reported() {
+    assert_eq!(animate(1), (false, String::new()));
+}
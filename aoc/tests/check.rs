@@ -0,0 +1,117 @@
+use aoc::{days::find, examples::load_examples};
+use aoc_common::check::Severity::{self, Error, Warning};
+
+/// Severity, line and message of every problem found in the input of the day.
+fn problems(day: u8, input: &str) -> Vec<(Severity, Option<usize>, String)> {
+    (find(day).unwrap().check)(input)
+        .into_iter()
+        .map(|diagnostic| {
+            assert_eq!(diagnostic.problem.day, Some(day));
+            (
+                diagnostic.severity,
+                diagnostic.problem.line,
+                diagnostic.problem.message,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn examples_have_no_problems() {
+    for day in 1..=7 {
+        for example in load_examples(day).unwrap() {
+            assert_eq!(problems(day, &example.input), vec![], "day {day:02}");
+        }
+    }
+}
+
+#[test]
+fn every_problem_is_reported() {
+    let inputs = [
+        (1, "100\nabc\n200\n\n-\n300\n"),
+        (2, "A X\nB W\nA Y\nE Z\n"),
+        (3, "abca\nabcab\nxyzx\nabc1ab\n"),
+        (4, "2-4,6-8\n6-2,1-3\n1-x,2-3\n"),
+        (
+            7,
+            "$ cd /\n$ ls\ndir a\n$ rm a\n$ cd b\n12 c\nfile d\n12 e f\n$ lsx\n",
+        ),
+    ];
+    let expected = [
+        vec![
+            (Error, Some(2), "expected calories of an item, found 'abc'"),
+            (Error, Some(5), "expected calories of an item, found '-'"),
+        ],
+        vec![
+            (Error, Some(2), "unknown response 'W', expected X, Y or Z"),
+            (
+                Error,
+                Some(4),
+                "unknown opponent's choice 'E', expected A, B or C",
+            ),
+        ],
+        vec![
+            (
+                Error,
+                Some(2),
+                "rucksack has an odd number of items (5), compartments must be equal",
+            ),
+            (
+                Error,
+                Some(4),
+                "invalid item '1', items must be ASCII letters",
+            ),
+            (
+                Error,
+                None,
+                "there are 4 rucksacks, but elves are split into groups of three",
+            ),
+        ],
+        vec![
+            (
+                Warning,
+                Some(2),
+                "section range '6-2' is reversed, the first section comes after the last one",
+            ),
+            (Error, Some(3), "expected last section number, found 'x'"),
+        ],
+        vec![
+            (Error, Some(4), "Unknown command"),
+            (Error, Some(5), "no directory named 'b' in /"),
+            (Error, Some(7), "Invalid input - unknown node type"),
+            (
+                Error,
+                Some(8),
+                "Invalid input - unexpected text after the name",
+            ),
+            (Error, Some(9), "Unknown command"),
+        ],
+    ];
+
+    for ((day, input), expected) in inputs.into_iter().zip(expected) {
+        let expected = expected
+            .into_iter()
+            .map(|(severity, line, message)| (severity, line, String::from(message)))
+            .collect::<Vec<_>>();
+        assert_eq!(problems(day, input), expected, "day {day:02}");
+    }
+}
+
+#[test]
+fn datastream_problems_are_warnings() {
+    assert_eq!(
+        problems(6, "  aaaAbcd!\n"),
+        vec![
+            (
+                Warning,
+                Some(1),
+                String::from("'A' is not a lowercase letter (2 such characters in the line)")
+            ),
+            (
+                Warning,
+                None,
+                String::from("there's no start-of-message marker, the answer is 0")
+            ),
+        ]
+    );
+}
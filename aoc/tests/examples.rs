@@ -0,0 +1,47 @@
+use aoc::{days::DAYS, examples::load_examples};
+
+#[test]
+fn every_day_has_examples() {
+    for day in DAYS {
+        let examples = load_examples(day.number).unwrap();
+        assert!(
+            !examples.is_empty(),
+            "day {:02} has no examples",
+            day.number
+        );
+    }
+}
+
+#[test]
+fn examples_give_expected_answers() {
+    let mut failures = vec![];
+
+    for day in DAYS {
+        for example in load_examples(day.number).unwrap() {
+            let parts = example
+                .expected
+                .iter()
+                .map(|&(part, _)| part)
+                .collect::<Vec<_>>();
+
+            match (day.solve)(&example.input, &parts) {
+                Ok(solved) => {
+                    for (part, expected) in &example.expected {
+                        let answer = solved.answer(*part).unwrap();
+                        if answer.to_string() != *expected {
+                            failures.push(format!(
+                                "day {:02} {} part {part}: expected {expected}, got {answer}",
+                                day.number, example.name
+                            ));
+                        }
+                    }
+                }
+                Err(error) => {
+                    failures.push(format!("day {:02} {}: {error}", day.number, example.name))
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
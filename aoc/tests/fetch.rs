@@ -0,0 +1,172 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::fetch::{FetchConfig, FetchError, FetchOutcome, Fetcher};
+
+/// Request seen by the stub server, as (path, cookie header).
+type Request = (String, Option<String>);
+
+/// Serves canned responses on a local port: inputs of days listed in `inputs`,
+/// 404 for everything else. Returns the base URL and the log of received requests.
+fn stub_server(inputs: &'static [(u8, &'static str)]) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+
+    let log = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let path = request_line
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+
+            let mut cookie = None;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("cookie") {
+                        cookie = Some(value.trim().to_string());
+                    }
+                }
+            }
+            log.lock().unwrap().push((path.clone(), cookie));
+
+            let input = inputs
+                .iter()
+                .find(|(day, _)| path == format!("/2022/day/{day}/input"));
+            let (status, body) = match input {
+                Some((_, input)) => ("200 OK", input.to_string()),
+                None => (
+                    "404 Not Found",
+                    String::from(
+                        "Please don't repeatedly request this endpoint before it unlocks!",
+                    ),
+                ),
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+fn config(base_url: &str, min_interval_secs: f64) -> FetchConfig {
+    FetchConfig {
+        session: Some(String::from("c0ffee")),
+        base_url: base_url.to_string(),
+        min_interval_secs,
+    }
+}
+
+#[test]
+fn input_is_downloaded_once_and_cached() {
+    let (base_url, requests) = stub_server(&[(1, "1000\n2000\n\n3000\n")]);
+    let directory = tempfile::tempdir().unwrap();
+    let fetcher = Fetcher::new(
+        config(&base_url, 0.0),
+        directory.path().join("last_request"),
+    );
+    let destination = directory.path().join("01").join("input");
+
+    assert_eq!(
+        fetcher.fetch(1, &destination).unwrap(),
+        FetchOutcome::Downloaded
+    );
+    assert_eq!(
+        fs::read_to_string(&destination).unwrap(),
+        "1000\n2000\n\n3000\n"
+    );
+    assert_eq!(
+        fetcher.fetch(1, &destination).unwrap(),
+        FetchOutcome::Cached
+    );
+
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![(
+            String::from("/2022/day/1/input"),
+            Some(String::from("session=c0ffee"))
+        )]
+    );
+}
+
+#[test]
+fn failed_download_is_reported_and_not_cached() {
+    let (base_url, requests) = stub_server(&[]);
+    let directory = tempfile::tempdir().unwrap();
+    let fetcher = Fetcher::new(
+        config(&base_url, 0.0),
+        directory.path().join("last_request"),
+    );
+    let destination = directory.path().join("25").join("input");
+
+    match fetcher.fetch(25, &destination) {
+        Err(FetchError::Http { status, message }) => {
+            assert_eq!(status, 404);
+            assert!(message.starts_with("Please don't"));
+        }
+        other => panic!("expected HTTP error, got {other:?}"),
+    }
+    assert!(!destination.exists());
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn missing_session_is_reported_before_any_request() {
+    let (base_url, requests) = stub_server(&[(1, "1\n")]);
+    let directory = tempfile::tempdir().unwrap();
+    let fetcher = Fetcher::new(
+        FetchConfig {
+            session: None,
+            ..config(&base_url, 0.0)
+        },
+        directory.path().join("last_request"),
+    );
+
+    assert!(matches!(
+        fetcher.fetch(1, &directory.path().join("input")),
+        Err(FetchError::MissingSession)
+    ));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn requests_are_spaced_by_minimum_interval() {
+    let (base_url, requests) = stub_server(&[(1, "1\n"), (2, "A Y\n")]);
+    let directory = tempfile::tempdir().unwrap();
+    let fetcher = Fetcher::new(
+        config(&base_url, 0.3),
+        directory.path().join("last_request"),
+    );
+
+    let start = Instant::now();
+    fetcher.fetch(1, &directory.path().join("01")).unwrap();
+    fetcher.fetch(2, &directory.path().join("02")).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
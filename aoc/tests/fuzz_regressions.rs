@@ -0,0 +1,37 @@
+use std::fs;
+
+use aoc::days::DAYS;
+use aoc_common::{input::workspace_dir, Part};
+
+/// Inputs that used to make a day panic are kept in `fuzz/regressions/NN/`.
+/// Every one of them must be either solved or rejected with an error.
+#[test]
+fn fuzzer_findings_do_not_panic() {
+    let regressions = workspace_dir().join("fuzz").join("regressions");
+    let mut checked = 0;
+
+    for day in DAYS {
+        let directory = regressions.join(format!("{:02}", day.number));
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let input = fs::read_to_string(&path).unwrap();
+            // a panic fails the test, and the message names the input
+            let result = std::panic::catch_unwind(|| {
+                (day.check)(&input);
+                (day.solve)(&input, &Part::ALL)
+            });
+            assert!(result.is_ok(), "{} panicked", path.display());
+            checked += 1;
+        }
+    }
+
+    assert!(
+        checked > 0,
+        "no regression inputs in {}",
+        regressions.display()
+    );
+}
@@ -0,0 +1,144 @@
+use std::{fs, io, path::Path};
+
+use aoc::scaffold::{new_day, TEMPLATE_PARTS};
+use aoc_common::input::workspace_dir;
+
+/// Copies the parts of the workspace touched by the generator into `target`.
+fn copy_workspace(target: &Path) {
+    let workspace = workspace_dir();
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+        fs::create_dir_all(target.join(file).parent().unwrap()).unwrap();
+        fs::copy(workspace.join(file), target.join(file)).unwrap();
+    }
+    // only the parts a new day is made of, build output of the template is not copied
+    for part in TEMPLATE_PARTS {
+        copy(
+            &workspace.join("template").join(part),
+            &target.join("template").join(part),
+        );
+    }
+}
+
+fn copy(source: &Path, target: &Path) {
+    if source.is_dir() {
+        fs::create_dir_all(target).unwrap();
+        for entry in fs::read_dir(source).unwrap() {
+            let path = entry.unwrap().path();
+            copy(&path, &target.join(path.file_name().unwrap()));
+        }
+    } else {
+        fs::create_dir_all(target.parent().unwrap()).unwrap();
+        fs::copy(source, target).unwrap();
+    }
+}
+
+#[test]
+fn new_day_is_created_and_registered() {
+    let workspace = tempfile::tempdir().unwrap();
+    let workspace = workspace.path();
+    copy_workspace(workspace);
+
+    new_day(workspace, 8).unwrap();
+
+    let manifest = fs::read_to_string(workspace.join("08/Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"aoc_2022_08\""));
+    assert!(manifest.contains("aoc_common = { path = \"../aoc_common\" }"));
+
+    let source = fs::read_to_string(workspace.join("08/src/lib.rs")).unwrap();
+    assert!(source.contains("pub struct Day08;"));
+    assert!(source.contains("impl Solution for Day08"));
+    assert!(source.contains("const DAY: u8 = 8;"));
+    assert!(source.contains("#[cfg(test)]"));
+    assert!(!source.contains("XX"));
+
+    for fixture in ["example.input", "example.expected"] {
+        assert_eq!(
+            fs::read_to_string(workspace.join("08/examples").join(fixture)).unwrap(),
+            ""
+        );
+    }
+
+    let workspace_manifest = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+    assert!(workspace_manifest.contains("\"07\", \"08\"]"));
+
+    let runner_manifest = fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap();
+    assert!(runner_manifest
+        .contains("aoc_2022_07 = { path = \"../07\" }\naoc_2022_08 = { path = \"../08\" }\n"));
+
+    let days = fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap();
+    assert!(days.contains("    day!(aoc_2022_07::Day07),\n    day!(aoc_2022_08::Day08),\n];"));
+
+    // files are moved in place once written
+    assert!(!workspace.join("08/src/lib.rs.new").exists());
+    assert!(!workspace.join("Cargo.toml.new").exists());
+}
+
+#[test]
+fn build_output_of_the_template_is_ignored() {
+    let workspace = tempfile::tempdir().unwrap();
+    let workspace = workspace.path();
+    copy_workspace(workspace);
+    fs::create_dir_all(workspace.join("template/target")).unwrap();
+    fs::write(
+        workspace.join("template/target/foo.bin"),
+        [0xff, 0xfe, 0x00],
+    )
+    .unwrap();
+    fs::write(workspace.join("template/Cargo.lock"), "# generated").unwrap();
+
+    new_day(workspace, 8).unwrap();
+
+    assert!(workspace.join("08/src/lib.rs").exists());
+    assert!(!workspace.join("08/target").exists());
+    assert!(!workspace.join("08/Cargo.lock").exists());
+}
+
+#[test]
+fn fetched_input_is_kept() {
+    let workspace = tempfile::tempdir().unwrap();
+    let workspace = workspace.path();
+    copy_workspace(workspace);
+    // `aoc fetch --day 8` creates the directory of the day, with only the input
+    fs::create_dir_all(workspace.join("08")).unwrap();
+    fs::write(workspace.join("08/input"), "fetched\n").unwrap();
+
+    new_day(workspace, 8).unwrap();
+
+    assert!(workspace.join("08/src/lib.rs").exists());
+    assert_eq!(
+        fs::read_to_string(workspace.join("08/input")).unwrap(),
+        "fetched\n"
+    );
+}
+
+#[test]
+fn existing_day_is_not_overwritten() {
+    let workspace = tempfile::tempdir().unwrap();
+    let workspace = workspace.path();
+    copy_workspace(workspace);
+    fs::create_dir_all(workspace.join("07/src")).unwrap();
+    fs::write(workspace.join("07/src/lib.rs"), "// solved").unwrap();
+    let manifest = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+
+    let error = new_day(workspace, 7).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(
+        fs::read_to_string(workspace.join("07/src/lib.rs")).unwrap(),
+        "// solved"
+    );
+    assert_eq!(
+        fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+        manifest
+    );
+}
+
+#[test]
+fn already_registered_day_leaves_workspace_untouched() {
+    let workspace = tempfile::tempdir().unwrap();
+    let workspace = workspace.path();
+    copy_workspace(workspace);
+
+    // registered in the runner, but the directory is gone
+    assert!(new_day(workspace, 3).is_err());
+    assert!(!workspace.join("03").exists());
+}
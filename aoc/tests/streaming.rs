@@ -0,0 +1,118 @@
+use std::fs;
+
+use aoc::{
+    days::{Day, DAYS},
+    examples::load_examples,
+    generate::generate,
+};
+use aoc_common::{
+    input::{workspace_dir, InputSource},
+    Part,
+};
+
+/// Inputs that some day rejects, every streaming day must reject them the same way.
+const MALFORMED: &[&str] = &[
+    "1\n2\nx\n",
+    "A Q\n",
+    "A X\nB\n",
+    "abcDabcd\n",
+    "aa\nbb\n",
+    "aa\nbb\ncc\n",
+    "2-4,6\n",
+    "9223372036854775807\n1\n",
+    "\n\n",
+    "\u{a0}abcd\u{a0}\n",
+    "\n[[1, 2], [3]]\n",
+    "[[1], [2, -3]]\n",
+    "elf,1\nelf,x\n",
+    "\nelf_id,item_calories\na,1\nb,5\n\na,7\n",
+    "a,9223372036854775807\nb,1\na,1\n",
+    "a,1\nb\n",
+];
+
+/// Every input a day can be checked with: examples, generated inputs and fuzzer findings.
+fn inputs(day: &Day) -> Vec<String> {
+    let mut inputs = load_examples(day.number)
+        .unwrap()
+        .into_iter()
+        .map(|example| example.input)
+        .collect::<Vec<_>>();
+    for size in [1, 2, 10, 1000] {
+        for seed in 0..3 {
+            inputs.extend(generate(day.number, size, seed));
+        }
+    }
+    let regressions = workspace_dir()
+        .join("fuzz")
+        .join("regressions")
+        .join(format!("{:02}", day.number));
+    if let Ok(entries) = fs::read_dir(regressions) {
+        for entry in entries {
+            inputs.push(fs::read_to_string(entry.unwrap().path()).unwrap());
+        }
+    }
+    inputs.extend(MALFORMED.iter().map(|input| input.to_string()));
+    inputs
+}
+
+#[test]
+fn streamed_answers_are_the_same_as_in_memory() {
+    let mut checked_days = 0;
+
+    for day in DAYS {
+        let Some(stream) = day.stream else {
+            continue;
+        };
+        checked_days += 1;
+
+        for input in inputs(day) {
+            let in_memory = (day.solve)(&input, &Part::ALL).map(|solved| solved.answers);
+            let streamed = stream(&mut input.as_bytes(), &Part::ALL).map(|solved| solved.answers);
+
+            match (in_memory, streamed) {
+                (Ok(in_memory), Ok(streamed)) => {
+                    let answers = |answers: Vec<aoc::days::PartAnswer>| {
+                        answers
+                            .into_iter()
+                            .map(|answer| (answer.part, answer.answer))
+                            .collect::<Vec<_>>()
+                    };
+                    assert_eq!(
+                        answers(streamed),
+                        answers(in_memory),
+                        "day {:02}, input {input:?}",
+                        day.number
+                    );
+                }
+                (Err(in_memory), Err(streamed)) => assert_eq!(
+                    streamed.to_string(),
+                    in_memory.to_string(),
+                    "day {:02}, input {input:?}",
+                    day.number
+                ),
+                (in_memory, streamed) => panic!(
+                    "day {:02}, input {input:?}: {in_memory:?} in memory, {streamed:?} streamed",
+                    day.number
+                ),
+            }
+        }
+    }
+
+    assert_eq!(checked_days, 5);
+}
+
+#[test]
+fn streamed_input_is_read_from_file() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("input");
+    fs::write(&path, "1000\n2000\n\n4000\n\n5000\n6000\n").unwrap();
+    let source = InputSource::File(path);
+    let day = DAYS.iter().find(|day| day.number == 1).unwrap();
+
+    let solved = day.stream_from(&source, &[Part::Two]).unwrap();
+    assert_eq!(solved.answer(Part::Two).unwrap().to_string(), "18000");
+    assert_eq!(solved.answer(Part::One), None);
+
+    let missing = InputSource::File(directory.path().join("missing"));
+    assert!(day.stream_from(&missing, &Part::ALL).is_err());
+}
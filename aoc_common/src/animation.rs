@@ -0,0 +1,64 @@
+use std::{io, ops::Range};
+
+/// Characters of a line of a frame to draw with emphasis.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Highlight {
+    pub line: usize,
+    /// Counted in characters, not bytes
+    pub columns: Range<usize>,
+}
+
+/// Single picture of an animation - a few lines of text with a caption.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Frame {
+    pub caption: String,
+    pub lines: Vec<String>,
+    pub highlights: Vec<Highlight>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, text: &str) -> Self {
+        Frame {
+            caption: caption.into(),
+            lines: text.lines().map(String::from).collect(),
+            highlights: vec![],
+        }
+    }
+
+    pub fn highlight(mut self, line: usize, columns: Range<usize>) -> Self {
+        self.highlights.push(Highlight { line, columns });
+        self
+    }
+
+    /// Whether the character at `column` of `line` is highlighted.
+    pub fn is_highlighted(&self, line: usize, column: usize) -> bool {
+        self.highlights
+            .iter()
+            .any(|highlight| highlight.line == line && highlight.columns.contains(&column))
+    }
+}
+
+/// Frames showing how a puzzle is solved, step by step. They're made only when needed,
+/// so long animations don't have to fit in memory.
+pub type Animation<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+/// Something frames can be shown on.
+pub trait Screen {
+    fn play(&mut self, frames: Animation<'_>) -> io::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::animation::Frame;
+
+    #[test]
+    fn frames_are_split_into_lines_and_highlighted() {
+        let frame = Frame::new("step 1", "[A]\n[B] [C]\n").highlight(1, 4..7);
+
+        assert_eq!(frame.lines, vec!["[A]", "[B] [C]"]);
+        assert!(frame.is_highlighted(1, 4));
+        assert!(frame.is_highlighted(1, 6));
+        assert!(!frame.is_highlighted(1, 7));
+        assert!(!frame.is_highlighted(0, 4));
+    }
+}
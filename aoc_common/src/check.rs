@@ -0,0 +1,163 @@
+use std::fmt;
+
+use crate::{error::ParseError, input::numbered_lines, Error, Result};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The input is accepted, but probably not what was meant
+    Warning,
+    /// The input is rejected
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Single problem found in the input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub problem: ParseError,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.problem)
+    }
+}
+
+impl From<Error> for Diagnostic {
+    fn from(error: Error) -> Self {
+        let problem = match error {
+            Error::Parse(problem) => problem,
+            Error::Io(error) => ParseError::new(error.to_string()),
+        };
+        Diagnostic {
+            severity: Severity::Error,
+            problem,
+        }
+    }
+}
+
+/// Problems found in the input, in the order they were found.
+/// Parsers that report problems here instead of returning the first one carry on after it,
+/// so all problems can be shown at once.
+#[derive(Debug, Default)]
+pub struct Report {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    pub fn error(&mut self, problem: impl Into<ParseError>) {
+        self.push(Severity::Error, problem.into());
+    }
+
+    pub fn warning(&mut self, problem: impl Into<ParseError>) {
+        self.push(Severity::Warning, problem.into());
+    }
+
+    fn push(&mut self, severity: Severity, problem: ParseError) {
+        self.diagnostics.push(Diagnostic { severity, problem });
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+
+    /// `value` if nothing was wrong, or the first error otherwise. Warnings don't count.
+    pub fn into_result<T>(self, value: T) -> Result<T> {
+        match self
+            .diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            Some(diagnostic) => Err(diagnostic.problem.into()),
+            None => Ok(value),
+        }
+    }
+
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+}
+
+/// Parses every non-empty line with `parser`, like `parse_lines`, but reports every line that
+/// can't be parsed instead of stopping at the first one. Returns the values of the other lines.
+pub fn check_lines<T, E, F>(input: &str, report: &mut Report, mut parser: F) -> Vec<T>
+where
+    E: Into<ParseError>,
+    F: FnMut(&str) -> std::result::Result<T, E>,
+{
+    numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(number, line)| match parser(line) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                report.error(error.into().at_line(number, line));
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        check::{check_lines, Report, Severity},
+        error::ParseError,
+        Error,
+    };
+
+    #[test]
+    fn all_invalid_lines_are_reported() {
+        let mut report = Report::default();
+        let parsed = check_lines("1\nx\n\n3\ny\n", &mut report, str::parse::<u32>);
+        let diagnostics = report.into_diagnostics();
+
+        assert_eq!(parsed, vec![1, 3]);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.severity, diagnostic.problem.line))
+                .collect::<Vec<_>>(),
+            vec![(Severity::Error, Some(2)), (Severity::Error, Some(5))]
+        );
+    }
+
+    #[test]
+    fn first_error_fails_the_result() {
+        let mut report = Report::default();
+        report.warning(ParseError::new("odd"));
+        assert!(!report.has_errors());
+        report.error(ParseError::new("first"));
+        report.error(ParseError::new("second"));
+
+        match report.into_result(()) {
+            Err(Error::Parse(error)) => assert_eq!(error.message, "first"),
+            other => panic!("expected parse error, got {other:?}"),
+        }
+
+        let mut report = Report::default();
+        report.warning(ParseError::new("odd"));
+        assert_eq!(report.into_result(5).unwrap(), 5);
+    }
+
+    #[test]
+    fn diagnostics_are_displayed_with_severity() {
+        let mut report = Report::default();
+        report.warning(ParseError::new("range is reversed").at_line(2, "6-2,1-1"));
+
+        assert_eq!(
+            report.into_diagnostics()[0].to_string(),
+            "warning: line 2: range is reversed\n2 | 6-2,1-1"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::{fmt, io, num::ParseIntError};
+
+/// Description of malformed input.
+/// Line parsers usually only know the column and the message - line number, its text
+/// and the day are filled in by the code calling them.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// 1-based line number
+    pub line: Option<usize>,
+    /// 1-based column number, counted in bytes
+    pub column: Option<usize>,
+    /// Text of the offending line
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line: None,
+            column: None,
+            text: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        self.line.get_or_insert(line);
+        self.text.get_or_insert_with(|| text.to_string());
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Points the error at `token`, which must be a subslice of `line`.
+    pub fn at_token(self, line: &str, token: &str) -> Self {
+        self.at_column(column_of(line, token))
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+/// 1-based column at which `token` starts in `line`.
+/// Falls back to the first column if `token` is not a part of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    if token_start >= line_start && token_start + token.len() <= line_start + line.len() {
+        token_start - line_start + 1
+    } else {
+        1
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {day:02}")),
+            self.line.map(|line| format!("line {line}")),
+            self.column.map(|column| format!("column {column}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>();
+
+        if location.is_empty() {
+            write!(f, "{}", self.message)?;
+        } else {
+            write!(f, "{}: {}", location.join(", "), self.message)?;
+        }
+
+        if let (Some(line), Some(text)) = (self.line, &self.text) {
+            let gutter = line.to_string().len();
+            write!(f, "\n{line} | {text}")?;
+            if let Some(column) = self.column {
+                write!(f, "\n{:gutter$} | {:>column$}", "", "^")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(error: ParseIntError) -> Self {
+        ParseError::new(error.to_string())
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse(message: impl Into<String>) -> Self {
+        Error::Parse(ParseError::new(message))
+    }
+
+    pub fn for_day(self, day: u8) -> Self {
+        match self {
+            Error::Parse(error) => Error::Parse(error.for_day(day)),
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {error}"),
+            Error::Parse(error) => write!(f, "parse error, {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{column_of, Error, ParseError};
+
+    #[test]
+    fn columns_of_tokens_are_found() {
+        let line = "move 1 from 2 to 3";
+        let tokens = line.split_whitespace().collect::<Vec<&str>>();
+        assert_eq!(column_of(line, tokens[0]), 1);
+        assert_eq!(column_of(line, tokens[3]), 13);
+        assert_eq!(column_of(line, "elsewhere"), 1);
+    }
+
+    #[test]
+    fn error_location_is_not_overwritten() {
+        let error = ParseError::new("bad")
+            .at_line(3, "abc")
+            .at_line(5, "def")
+            .for_day(2)
+            .for_day(4);
+
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.text.as_deref(), Some("abc"));
+        assert_eq!(error.day, Some(2));
+    }
+
+    #[test]
+    fn diagnostic_points_at_offending_column() {
+        let line = "move x from 2 to 3";
+        let error = ParseError::new("expected a number")
+            .at_token(line, &line[5..6])
+            .at_line(12, line)
+            .for_day(5);
+
+        assert_eq!(
+            Error::from(error).to_string(),
+            "parse error, day 05, line 12, column 6: expected a number\n\
+             12 | move x from 2 to 3\n   |      ^"
+        );
+    }
+
+    #[test]
+    fn error_without_location_shows_message_only() {
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+    }
+}
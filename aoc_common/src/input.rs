@@ -0,0 +1,462 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{error::ParseError, Error, Result};
+
+/// Environment variable pointing to a directory with puzzle inputs, laid out like
+/// the workspace - `$AOC_INPUT_DIR/NN/input`.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// Name of the input path argument meaning "read from standard input".
+pub const STDIN_ARGUMENT: &str = "-";
+
+/// Root directory of the workspace, the closest one with a `[workspace]` manifest at or above
+/// the current directory. Falls back to the workspace `aoc` was built in, if there's none.
+pub fn workspace_dir() -> PathBuf {
+    env::current_dir()
+        .ok()
+        .and_then(|directory| find_workspace(&directory))
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+}
+
+fn find_workspace(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|directory| {
+            fs::read_to_string(directory.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Directory of the day's crate, `NN` in the workspace root.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("{day:02}"))
+}
+
+/// Default location of the day's puzzle input, `NN/input` in the workspace root.
+pub fn default_input_path(day: u8) -> PathBuf {
+    day_input_path(workspace_dir(), day)
+}
+
+fn day_input_path(directory: impl AsRef<Path>, day: u8) -> PathBuf {
+    directory.as_ref().join(format!("{day:02}")).join("input")
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the input for `day`, in order of precedence: explicitly given path (or `-` for stdin),
+    /// directory from `AOC_INPUT_DIR` environment variable, default location in the workspace.
+    pub fn for_day(day: u8, explicit: Option<&str>) -> Self {
+        Self::resolve(day, explicit, env::var_os(INPUT_DIR_VARIABLE))
+    }
+
+    pub fn resolve(day: u8, explicit: Option<&str>, input_dir: Option<OsString>) -> Self {
+        match (explicit, input_dir) {
+            (Some(STDIN_ARGUMENT), _) => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(PathBuf::from(path)),
+            (None, Some(directory)) if !directory.is_empty() => {
+                InputSource::File(day_input_path(directory, day))
+            }
+            (None, _) => InputSource::File(default_input_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let input = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        input.map_err(|error| {
+            Error::Io(io::Error::new(
+                error.kind(),
+                format!("cannot read input from {self}: {error}"),
+            ))
+        })
+    }
+
+    /// Opens the input for reading bit by bit, see [`stream_lines`].
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| {
+                    Error::Io(io::Error::new(
+                        error.kind(),
+                        format!("cannot read input from {self}: {error}"),
+                    ))
+                }),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "standard input"),
+        }
+    }
+}
+
+/// Splits the input into groups of lines separated by blank lines.
+/// Lines containing only whitespace count as blank.
+pub fn split_groups(input: &str) -> Vec<Vec<&str>> {
+    let mut groups = vec![];
+    let mut current = vec![];
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                groups.push(current);
+                current = vec![];
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    if !current.is_empty() {
+        groups.push(current);
+    }
+
+    groups
+}
+
+/// Iterates over lines of the input together with their 1-based numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+/// Parses every non-empty line of the input with `parser`, stopping at the first error.
+/// Errors are annotated with the number and text of the line that caused them.
+pub fn parse_lines<T, E, F>(input: &str, mut parser: F) -> Result<Vec<T>>
+where
+    E: Into<ParseError>,
+    F: FnMut(&str) -> std::result::Result<T, E>,
+{
+    numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            parser(line).map_err(|error| Error::from(error.into().at_line(number, line)))
+        })
+        .collect()
+}
+
+/// Lines of a reader with their 1-based numbers, split the same way as by [`numbered_lines`].
+/// Only the current line is kept in memory.
+pub struct StreamedLines<R> {
+    reader: R,
+    number: usize,
+}
+
+impl<R: BufRead> Iterator for StreamedLines<R> {
+    type Item = Result<(usize, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                // like `str::lines`, a lone `\r` is stripped only before `\n`
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                self.number += 1;
+                Some(Ok((self.number, line)))
+            }
+            Err(error) => Some(Err(error.into())),
+        }
+    }
+}
+
+/// Streaming counterpart of [`numbered_lines`].
+pub fn stream_lines<R: BufRead>(reader: R) -> StreamedLines<R> {
+    StreamedLines { reader, number: 0 }
+}
+
+/// Groups of numbered lines separated by blank lines, read one group at a time.
+/// Streaming counterpart of [`split_groups`].
+pub struct StreamedGroups<R> {
+    lines: StreamedLines<R>,
+}
+
+impl<R: BufRead> Iterator for StreamedGroups<R> {
+    type Item = Result<Vec<(usize, String)>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = vec![];
+        for line in self.lines.by_ref() {
+            match line {
+                Ok((_, line)) if line.trim().is_empty() => {
+                    if !group.is_empty() {
+                        return Some(Ok(group));
+                    }
+                }
+                Ok(line) => group.push(line),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+        (!group.is_empty()).then_some(Ok(group))
+    }
+}
+
+pub fn stream_groups<R: BufRead>(reader: R) -> StreamedGroups<R> {
+    StreamedGroups {
+        lines: stream_lines(reader),
+    }
+}
+
+/// Streaming counterpart of [`parse_lines`], parsed values are yielded one by one.
+pub fn stream_parsed_lines<R, T, E, F>(reader: R, mut parser: F) -> impl Iterator<Item = Result<T>>
+where
+    R: BufRead,
+    E: Into<ParseError>,
+    F: FnMut(&str) -> std::result::Result<T, E>,
+{
+    stream_lines(reader)
+        .filter(|line| !matches!(line, Ok((_, line)) if line.trim().is_empty()))
+        .map(move |line| {
+            let (number, line) = line?;
+            parser(&line).map_err(|error| Error::from(error.into().at_line(number, &line)))
+        })
+}
+
+/// Calls `f` with every byte of the reader, for inputs that may not be split into lines at all.
+/// Like reading the whole input into a string, fails if it's not valid UTF-8.
+pub fn stream_bytes<R: BufRead>(mut reader: R, mut f: impl FnMut(u8)) -> Result<()> {
+    // bytes of a character that's split between reads wait for the rest of it
+    let mut pending = vec![];
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            break;
+        }
+        pending.extend_from_slice(chunk);
+        let read = chunk.len();
+        reader.consume(read);
+
+        let valid = match std::str::from_utf8(&pending) {
+            Ok(_) => pending.len(),
+            Err(error) if error.error_len().is_none() => error.valid_up_to(),
+            Err(_) => return Err(invalid_utf8()),
+        };
+        pending.drain(..valid).for_each(&mut f);
+    }
+
+    if pending.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid_utf8())
+    }
+}
+
+fn invalid_utf8() -> Error {
+    Error::Io(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    ))
+}
+
+/// Parses `token`, a part of `line`, pointing at it if it's not valid.
+/// `what` describes the expected value in the error message.
+pub fn parse_token<T: FromStr>(
+    line: &str,
+    token: &str,
+    what: &str,
+) -> std::result::Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        ParseError::new(format!("expected {what}, found '{token}'")).at_token(line, token)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::OsString,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::{
+        error::ParseError,
+        input::{
+            default_input_path, find_workspace, numbered_lines, parse_lines, parse_token,
+            split_groups, stream_bytes, stream_groups, stream_lines, stream_parsed_lines,
+            InputSource,
+        },
+        Error,
+    };
+
+    #[test]
+    fn input_source_is_resolved_by_precedence() {
+        let input_dir = Some(OsString::from("/srv/aoc"));
+
+        assert_eq!(
+            InputSource::resolve(5, Some("my_input.txt"), input_dir.clone()),
+            InputSource::File(PathBuf::from("my_input.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve(5, Some("-"), input_dir.clone()),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve(5, None, input_dir),
+            InputSource::File(PathBuf::from("/srv/aoc/05/input"))
+        );
+        assert_eq!(
+            InputSource::resolve(5, None, Some(OsString::new())),
+            InputSource::File(default_input_path(5))
+        );
+        assert_eq!(
+            InputSource::resolve(12, None, None),
+            InputSource::File(default_input_path(12))
+        );
+        assert!(default_input_path(12).ends_with("12/input"));
+    }
+
+    #[test]
+    fn workspace_is_found_above_the_directory() {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let workspace = crate_dir.parent().map(Path::to_path_buf);
+
+        assert_eq!(find_workspace(crate_dir), workspace);
+        assert_eq!(find_workspace(&crate_dir.join("src")), workspace);
+        assert_eq!(find_workspace(Path::new("/")), None);
+    }
+
+    #[test]
+    fn input_is_read_from_file() {
+        let path = std::env::temp_dir().join(format!("aoc_input_{}", std::process::id()));
+        fs::write(&path, "1\n2\n").unwrap();
+
+        let source = InputSource::File(path.clone());
+        let input = source.read();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(input.unwrap(), "1\n2\n");
+        assert!(InputSource::File(path).read().is_err());
+    }
+
+    #[test]
+    fn groups_are_split_on_blank_lines() {
+        let input = "1\n2\n\n3\n   \n4\n5\n\n\n";
+        assert_eq!(
+            split_groups(input),
+            vec![vec!["1", "2"], vec!["3"], vec!["4", "5"]]
+        );
+    }
+
+    #[test]
+    fn empty_input_has_no_groups() {
+        assert!(split_groups("").is_empty());
+        assert!(split_groups("\n\n").is_empty());
+    }
+
+    #[test]
+    fn lines_are_parsed_skipping_empty_ones() {
+        let parsed = parse_lines("1\n\n2\n3\n", str::parse::<u32>);
+        assert_eq!(parsed.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn line_parsing_errors_have_line_number_and_text() {
+        match parse_lines("1\n\nx\n3\n", str::parse::<u32>) {
+            Err(Error::Parse(error)) => {
+                assert_eq!(error.line, Some(3));
+                assert_eq!(error.text.as_deref(), Some("x"));
+            }
+            other => panic!("expected parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_tokens_are_pointed_at() {
+        let line = "12-x4";
+        assert_eq!(parse_token::<u32>(line, &line[..2], "a number"), Ok(12));
+        assert_eq!(
+            parse_token::<u32>(line, &line[3..], "a number"),
+            Err(ParseError::new("expected a number, found 'x4'").at_column(4))
+        );
+    }
+
+    #[test]
+    fn streamed_lines_are_split_like_in_memory() {
+        let inputs = ["", "\n", "1\n2", "1\r\n\r\n2\r\n", "a\rb\n\n", "x\r"];
+
+        for input in inputs {
+            let streamed = stream_lines(input.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let in_memory = numbered_lines(input)
+                .map(|(number, line)| (number, line.to_string()))
+                .collect::<Vec<_>>();
+            assert_eq!(streamed, in_memory, "input {input:?}");
+        }
+    }
+
+    #[test]
+    fn streamed_groups_are_split_on_blank_lines() {
+        let input = "1\n2\n\n3\n   \n4\n5\n\n\n";
+        let groups = stream_groups(input.as_bytes())
+            .map(|group| {
+                group
+                    .unwrap()
+                    .into_iter()
+                    .map(|(_, line)| line)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(groups, split_groups(input));
+    }
+
+    #[test]
+    fn streamed_line_parsing_errors_have_line_number_and_text() {
+        let mut parsed = stream_parsed_lines("1\n\nx\n3\n".as_bytes(), str::parse::<u32>);
+
+        assert_eq!(parsed.next().unwrap().unwrap(), 1);
+        match parsed.next() {
+            Some(Err(Error::Parse(error))) => {
+                assert_eq!(error.line, Some(3));
+                assert_eq!(error.text.as_deref(), Some("x"));
+            }
+            other => panic!("expected parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn streamed_bytes_must_be_valid_utf8() {
+        // a tiny buffer splits the multi-byte character between reads
+        let input = "zażółć";
+        let mut bytes = vec![];
+        let reader = std::io::BufReader::with_capacity(1, input.as_bytes());
+        stream_bytes(reader, |byte| bytes.push(byte)).unwrap();
+        assert_eq!(bytes, input.as_bytes());
+
+        assert!(stream_bytes(&b"ab\xff"[..], |_| ()).is_err());
+        assert!(stream_bytes(&"ż".as_bytes()[..1], |_| ()).is_err());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod animation;
+pub mod check;
+pub mod error;
+pub mod input;
+pub mod solution;
+
+pub use error::{Error, Result};
+pub use solution::{Answer, Part, Solution, StreamingSolution};
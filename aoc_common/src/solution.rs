@@ -0,0 +1,167 @@
+use std::{fmt, io::BufRead};
+
+use crate::{
+    animation::Animation,
+    check::{Diagnostic, Report},
+    Result,
+};
+
+/// Answer to a single part of a puzzle.
+/// Most puzzles are answered with a number, but some (like day 05) expect a text.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(i64::from(number))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u8, u32);
+
+/// Numbers that may not fit in `i64` become text, so they're still shown exactly.
+macro_rules! answer_from_large_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    i64::try_from(number)
+                        .map_or_else(|_| Answer::Text(number.to_string()), Answer::Number)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_large_number!(u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(part: u8) -> std::result::Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(part),
+        }
+    }
+}
+
+/// Common interface of every day's solution.
+/// The input is parsed once, and the same parsed input is used to answer both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    fn answer(input: &Self::Input, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Human-readable description of the input or the way it's solved, shown only on request.
+    /// It's never a part of the answer.
+    fn details(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Every problem with the input, not only the first one. By default, it's the error of `parse`.
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let mut report = Report::default();
+        if let Err(error) = Self::parse(input) {
+            report.error(Diagnostic::from(error).problem);
+        }
+        report.into_diagnostics()
+    }
+
+    /// Frames showing the solution step by step, for days that can be animated.
+    fn animation(_input: &Self::Input) -> Option<Animation<'_>> {
+        None
+    }
+}
+
+/// Solution that can answer both parts in a single pass over the input, without keeping all of it
+/// in memory. Answers and errors must be the same as the ones of parsing the whole input.
+pub trait StreamingSolution: Solution {
+    fn solve_stream<R: BufRead>(reader: R) -> Result<[Answer; 2]>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{Answer, Part};
+
+    #[test]
+    fn answers_are_displayed_as_plain_values() {
+        assert_eq!(Answer::from(1234usize).to_string(), "1234");
+        assert_eq!(Answer::from(-12i64).to_string(), "-12");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+        assert_eq!(
+            Answer::from(String::from("MCD")),
+            Answer::Text("MCD".into())
+        );
+    }
+
+    #[test]
+    fn parts_are_converted_from_numbers() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3), Err(3));
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+}
@@ -0,0 +1,14 @@
+use std::{env, path::PathBuf};
+
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // the copy in include/ is kept in the repository, tests/header.rs checks it's up to date
+    cbindgen::generate(&crate_dir)
+        .expect("cannot generate the C header")
+        .write_to_file(out_dir.join("aoc_ffi.h"));
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
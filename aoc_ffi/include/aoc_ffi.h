@@ -0,0 +1,90 @@
+#ifndef AOC_FFI_H
+#define AOC_FFI_H
+
+/* Generated by cbindgen from aoc_ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of `aoc_solve`.
+ */
+enum AocStatus
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * The answer was written to the output buffer.
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * The day is not solved yet.
+   */
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  /**
+   * The part is neither 1 nor 2.
+   */
+  AOC_STATUS_INVALID_PART = 2,
+  /**
+   * A required pointer is null.
+   */
+  AOC_STATUS_NULL_POINTER = 3,
+  /**
+   * The input is not valid UTF-8.
+   */
+  AOC_STATUS_INVALID_UTF8 = 4,
+  /**
+   * The input is malformed.
+   */
+  AOC_STATUS_PARSE_ERROR = 5,
+  /**
+   * The input could not be read.
+   */
+  AOC_STATUS_IO_ERROR = 6,
+  /**
+   * The output buffer is too small, `out_len` holds the required size.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 7,
+  /**
+   * The solver panicked.
+   */
+  AOC_STATUS_PANIC = 8,
+};
+#ifndef __cplusplus
+typedef int32_t AocStatus;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solves `part` (1 or 2) of `day` for the input of `input_len` bytes at `input_ptr`.
+ *
+ * `*out_len` is the size of `out_buf` in bytes. On success, the answer is written there as
+ * a NUL-terminated string and `*out_len` is set to its length. If it doesn't fit,
+ * `AOC_STATUS_BUFFER_TOO_SMALL` is returned and `*out_len` is set to the size it needs, NUL
+ * included. On other errors, the buffer holds the error message, cut to fit.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `input_len` readable bytes (it may be null if `input_len` is 0),
+ * `out_buf` to `*out_len` writable bytes (it may be null if `*out_len` is 0), and `out_len`
+ * must be a valid pointer.
+ */
+AocStatus aoc_solve(uint8_t day,
+                    uint8_t part,
+                    const uint8_t *input_ptr,
+                    size_t input_len,
+                    char *out_buf,
+                    size_t *out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_FFI_H */
@@ -0,0 +1,218 @@
+//! C interface of the solvers. The header, `include/aoc_ffi.h`, is generated by the build script
+//! into `OUT_DIR`, the committed copy is checked against it by `tests/header.rs`.
+
+use std::{ffi::c_char, panic, ptr, slice};
+
+use aoc::{days, pool::panic_message};
+use aoc_common::{Error, Part};
+
+/// Result of `aoc_solve`.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AocStatus {
+    /// The answer was written to the output buffer.
+    Ok = 0,
+    /// The day is not solved yet.
+    UnknownDay = 1,
+    /// The part is neither 1 nor 2.
+    InvalidPart = 2,
+    /// A required pointer is null.
+    NullPointer = 3,
+    /// The input is not valid UTF-8.
+    InvalidUtf8 = 4,
+    /// The input is malformed.
+    ParseError = 5,
+    /// The input could not be read.
+    IoError = 6,
+    /// The output buffer is too small, `out_len` holds the required size.
+    BufferTooSmall = 7,
+    /// The solver panicked.
+    Panic = 8,
+}
+
+impl From<&Error> for AocStatus {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::Io(_) => AocStatus::IoError,
+            Error::Parse(_) => AocStatus::ParseError,
+        }
+    }
+}
+
+fn solve(day: u8, part: u8, input: &[u8]) -> Result<String, (AocStatus, String)> {
+    let solver = days::find(day).ok_or_else(|| {
+        (
+            AocStatus::UnknownDay,
+            format!("Day {day:02} is not solved yet"),
+        )
+    })?;
+    let part = Part::try_from(part).map_err(|part| {
+        (
+            AocStatus::InvalidPart,
+            format!("invalid part {part}, expected 1 or 2"),
+        )
+    })?;
+    let input = std::str::from_utf8(input)
+        .map_err(|error| (AocStatus::InvalidUtf8, format!("invalid input: {error}")))?;
+
+    // unwinding into C is undefined behavior
+    let solved = panic::catch_unwind(|| (solver.solve)(input, &[part]))
+        .map_err(|payload| (AocStatus::Panic, panic_message(payload)))?
+        .map_err(|error| (AocStatus::from(&error), error.to_string()))?;
+    Ok(solved
+        .answer(part)
+        .map(ToString::to_string)
+        .unwrap_or_default())
+}
+
+/// Copies `text` with a terminating NUL to the buffer of `capacity` bytes, cutting it if needed.
+/// Returns the number of bytes copied, without the NUL.
+unsafe fn write_truncated(text: &str, out_buf: *mut c_char, capacity: usize) -> usize {
+    if out_buf.is_null() || capacity == 0 {
+        return 0;
+    }
+    let length = text.len().min(capacity - 1);
+    ptr::copy_nonoverlapping(text.as_ptr(), out_buf.cast::<u8>(), length);
+    *out_buf.add(length) = 0;
+    length
+}
+
+/// Solves `part` (1 or 2) of `day` for the input of `input_len` bytes at `input_ptr`.
+///
+/// `*out_len` is the size of `out_buf` in bytes. On success, the answer is written there as
+/// a NUL-terminated string and `*out_len` is set to its length. If it doesn't fit,
+/// `AOC_STATUS_BUFFER_TOO_SMALL` is returned and `*out_len` is set to the size it needs, NUL
+/// included. On other errors, the buffer holds the error message, cut to fit.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes (it may be null if `input_len` is 0),
+/// `out_buf` to `*out_len` writable bytes (it may be null if `*out_len` is 0), and `out_len`
+/// must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> AocStatus {
+    if out_len.is_null() {
+        return AocStatus::NullPointer;
+    }
+    let capacity = *out_len;
+    if (input_ptr.is_null() && input_len > 0) || (out_buf.is_null() && capacity > 0) {
+        *out_len = write_truncated("null pointer given", out_buf, capacity);
+        return AocStatus::NullPointer;
+    }
+    let input = if input_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input_ptr, input_len)
+    };
+
+    match solve(day, part, input) {
+        Ok(answer) if answer.len() < capacity => {
+            *out_len = write_truncated(&answer, out_buf, capacity);
+            AocStatus::Ok
+        }
+        Ok(answer) => {
+            *out_len = answer.len() + 1;
+            AocStatus::BufferTooSmall
+        }
+        Err((status, message)) => {
+            *out_len = write_truncated(&message, out_buf, capacity);
+            status
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, ptr};
+
+    use crate::{aoc_solve, AocStatus};
+
+    /// Calls `aoc_solve` with a buffer of `capacity` bytes, returning the status,
+    /// the reported length and the string in the buffer.
+    fn call(day: u8, part: u8, input: &str, capacity: usize) -> (AocStatus, usize, String) {
+        let mut buffer = vec![0x7fu8; capacity];
+        let mut length = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                buffer.as_mut_ptr().cast(),
+                &mut length,
+            )
+        };
+        let text = CStr::from_bytes_until_nul(&buffer)
+            .map(|text| text.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        (status, length, text)
+    }
+
+    #[test]
+    fn answers_are_written_to_the_buffer() {
+        let input = "1000\n2000\n\n4000\n";
+        assert_eq!(
+            call(1, 1, input, 16),
+            (AocStatus::Ok, 4, String::from("4000"))
+        );
+        assert_eq!(
+            call(1, 2, input, 5),
+            (AocStatus::Ok, 4, String::from("7000"))
+        );
+        assert_eq!(
+            call(1, 2, input, 4),
+            (AocStatus::BufferTooSmall, 5, String::new())
+        );
+    }
+
+    #[test]
+    fn errors_are_mapped_to_statuses() {
+        let (status, length, message) = call(4, 1, "1-2\n", 64);
+        assert_eq!(status, AocStatus::ParseError);
+        assert_eq!(length, message.len());
+        assert!(message.contains("line 1"), "{message}");
+
+        assert_eq!(call(25, 1, "", 64).0, AocStatus::UnknownDay);
+        assert_eq!(call(1, 3, "", 64).0, AocStatus::InvalidPart);
+        // messages are cut to fit
+        assert_eq!(
+            call(1, 3, "", 8),
+            (AocStatus::InvalidPart, 7, String::from("invalid"))
+        );
+    }
+
+    #[test]
+    fn invalid_pointers_and_input_are_rejected() {
+        let input = b"1\n\xff\n";
+        let mut length = 0;
+        let status = unsafe {
+            aoc_solve(
+                1,
+                1,
+                input.as_ptr(),
+                input.len(),
+                ptr::null_mut(),
+                &mut length,
+            )
+        };
+        assert_eq!(status, AocStatus::InvalidUtf8);
+
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 4, ptr::null_mut(), &mut length) };
+        assert_eq!(status, AocStatus::NullPointer);
+
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 0, ptr::null_mut(), ptr::null_mut()) };
+        assert_eq!(status, AocStatus::NullPointer);
+
+        // empty input with no buffer asks for the size of the answer
+        let mut length = 0;
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 0, ptr::null_mut(), &mut length) };
+        assert_eq!((status, length), (AocStatus::BufferTooSmall, 2));
+    }
+}
@@ -0,0 +1,72 @@
+/* Solves both parts of a day through the C interface and checks the answers.
+ * Usage: solve <day> <input file> <part 1 answer> <part 2 answer>
+ */
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc_ffi.h"
+
+static int check_part(uint8_t day, uint8_t part, const char *input, size_t input_len,
+                      const char *expected) {
+    char answer[64];
+    size_t answer_len = sizeof(answer);
+    AocStatus status = aoc_solve(day, part, (const uint8_t *)input, input_len, answer, &answer_len);
+    if (status != AOC_STATUS_OK) {
+        fprintf(stderr, "part %d failed with status %d: %s\n", part, status, answer);
+        return 1;
+    }
+    if (answer_len != strlen(expected) || strcmp(answer, expected) != 0) {
+        fprintf(stderr, "part %d: expected %s, got %s\n", part, expected, answer);
+        return 1;
+    }
+    printf("part %d: %s\n", part, answer);
+    return 0;
+}
+
+static int check_errors(const char *input, size_t input_len) {
+    char message[256];
+    size_t message_len = sizeof(message);
+
+    if (aoc_solve(0, 1, (const uint8_t *)input, input_len, message, &message_len) !=
+        AOC_STATUS_UNKNOWN_DAY) {
+        fprintf(stderr, "day 0 was not rejected\n");
+        return 1;
+    }
+    message_len = sizeof(message);
+    if (aoc_solve(4, 1, (const uint8_t *)"1-2\n", 4, message, &message_len) !=
+        AOC_STATUS_PARSE_ERROR) {
+        fprintf(stderr, "malformed input was not rejected\n");
+        return 1;
+    }
+    printf("error: %s\n", message);
+
+    /* asking without a buffer gives the size of the answer */
+    message_len = 0;
+    if (aoc_solve(1, 1, (const uint8_t *)"1\n", 2, NULL, &message_len) !=
+            AOC_STATUS_BUFFER_TOO_SMALL ||
+        message_len != 2) {
+        fprintf(stderr, "size of the answer was not reported\n");
+        return 1;
+    }
+    return 0;
+}
+
+int main(int argc, char **argv) {
+    static char input[1 << 20];
+
+    if (argc != 5) {
+        fprintf(stderr, "usage: %s <day> <input file> <part 1 answer> <part 2 answer>\n", argv[0]);
+        return 2;
+    }
+    FILE *file = fopen(argv[2], "rb");
+    if (file == NULL) {
+        perror(argv[2]);
+        return 2;
+    }
+    size_t input_len = fread(input, 1, sizeof(input), file);
+    fclose(file);
+
+    uint8_t day = (uint8_t)atoi(argv[1]);
+    return check_part(day, 1, input, input_len, argv[3]) ||
+           check_part(day, 2, input, input_len, argv[4]) || check_errors(input, input_len);
+}
@@ -0,0 +1,87 @@
+#![cfg(unix)]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc::{days::DAYS, examples::load_examples};
+use aoc_common::Part;
+
+/// Directory with the built `cdylib`, the test executable lives in its `deps/`.
+fn library_dir() -> PathBuf {
+    let executable = env::current_exe().unwrap();
+    executable
+        .parent()
+        .and_then(Path::parent)
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Compiles `tests/c/solve.c` against the library, returning the path of the program.
+fn compile_c_program(output_dir: &Path) -> PathBuf {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_dir();
+    let program = output_dir.join("solve");
+    let compiler = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+
+    let status = Command::new(&compiler)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests").join("c").join("solve.c"))
+        .arg("-o")
+        .arg(&program)
+        .arg("-L")
+        .arg(&library_dir)
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-laoc_ffi")
+        .status()
+        .unwrap_or_else(|error| panic!("cannot run {compiler}: {error}"));
+    assert!(status.success(), "{compiler} failed with {status}");
+
+    program
+}
+
+#[test]
+fn c_program_solves_examples() {
+    let directory = tempfile::tempdir().unwrap();
+    let program = compile_c_program(directory.path());
+    let mut checked = 0;
+
+    for day in DAYS {
+        for example in load_examples(day.number).unwrap() {
+            let expected = |part| {
+                example
+                    .expected
+                    .iter()
+                    .find(|(expected_part, _)| *expected_part == part)
+                    .map(|(_, answer)| answer.clone())
+            };
+            let (Some(part1), Some(part2)) = (expected(Part::One), expected(Part::Two)) else {
+                continue;
+            };
+            let input = directory
+                .path()
+                .join(format!("{:02}_{}", day.number, example.name));
+            fs::write(&input, &example.input).unwrap();
+
+            let output = Command::new(&program)
+                .arg(day.number.to_string())
+                .arg(&input)
+                .args([&part1, &part2])
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "day {:02} {}: {}",
+                day.number,
+                example.name,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            checked += 1;
+        }
+    }
+
+    assert!(checked >= DAYS.len());
+}
@@ -0,0 +1,23 @@
+use std::{env, fs, path::Path};
+
+/// Header made by the build script from the current sources.
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/aoc_ffi.h"));
+
+#[test]
+fn committed_header_is_up_to_date() {
+    let committed_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("include")
+        .join("aoc_ffi.h");
+
+    if env::var_os("AOC_FFI_UPDATE_HEADER").is_some() {
+        fs::write(&committed_path, GENERATED).unwrap();
+        return;
+    }
+
+    let committed = fs::read_to_string(&committed_path).unwrap();
+    assert!(
+        committed == GENERATED,
+        "{} is out of date, run `AOC_FFI_UPDATE_HEADER=1 cargo test -p aoc_ffi` to regenerate it",
+        committed_path.display()
+    );
+}
@@ -0,0 +1,18 @@
+use aoc_common::{Part, Solution};
+
+/// Checks and parses the data as puzzle input and, if it's accepted, answers both parts.
+/// Any panic on the way is a bug - malformed input must be reported as an error.
+pub fn solve<S: Solution>(data: &[u8]) {
+    // inputs are read as UTF-8 text, anything else never reaches the parser
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    // checking carries on after errors, so it goes further into malformed input than parsing
+    S::check(input);
+    if let Ok(parsed) = S::parse(input) {
+        for part in Part::ALL {
+            S::answer(&parsed, part);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_common::{Answer, Result, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &String) -> Answer {
+        Answer::Number(0)
+    }
+
+    fn part2(_input: &String) -> Answer {
+        Answer::Number(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use crate::DayXX;
+
+    #[test]
+    fn input_is_parsed() {
+        assert!(DayXX::parse("").is_ok());
+    }
+}
@@ -1,8 +0,0 @@
-use std::fs;
-
-fn main() {
-    let _input = fs::read_to_string("./input").unwrap();
-}
-
-#[cfg(test)]
-mod tests {}